@@ -0,0 +1,139 @@
+//! Admission control for ZK backends
+//!
+//! [`crate::types::ResourceUsage`] already tracks `active_tasks`, `max_concurrent`, and
+//! `queue_depth`, but nothing enforces them: under burst load a backend can oversubscribe and
+//! starve. [`ConcurrencyGuard`] turns those descriptive fields into an actually enforced policy —
+//! a semaphore holding `max_concurrent` permits that [`crate::backend::ZkBackendExt`] uses to gate
+//! `prove`/`verify`.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use crate::error::{ZkError, ZkResult};
+use crate::types::ResourceUsage;
+
+/// Bounds the number of concurrent proving/verification tasks a backend will admit.
+///
+/// Callers acquire a [`ConcurrencyPermit`] before doing work; if no permit is immediately
+/// available the request either waits ([`ConcurrencyGuard::acquire`]) or fails fast
+/// ([`ConcurrencyGuard::try_acquire`]).
+#[derive(Debug)]
+pub struct ConcurrencyGuard {
+    semaphore: Semaphore,
+    max_concurrent: usize,
+    active_tasks: AtomicUsize,
+    queue_depth: AtomicUsize,
+}
+
+impl ConcurrencyGuard {
+    /// Create a guard that admits at most `max_concurrent` tasks at a time.
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            semaphore: Semaphore::new(max_concurrent),
+            max_concurrent,
+            active_tasks: AtomicUsize::new(0),
+            queue_depth: AtomicUsize::new(0),
+        }
+    }
+
+    /// Acquire a permit, waiting if none is immediately available.
+    ///
+    /// If `timeout` is set and elapses before a permit frees up, returns `ZkError::Timeout`
+    /// instead of continuing to wait; callers typically pass `config.proving_timeout` or
+    /// `config.verification_timeout` here.
+    pub async fn acquire(&self, timeout: Option<Duration>) -> ZkResult<ConcurrencyPermit<'_>> {
+        self.queue_depth.fetch_add(1, Ordering::SeqCst);
+        let acquired = match timeout {
+            Some(duration) => tokio::time::timeout(duration, self.semaphore.acquire())
+                .await
+                .map_err(|_| ZkError::Timeout(format!("admission wait exceeded {duration:?}")))
+                .and_then(|r| r.map_err(|_| ZkError::Backend("concurrency guard closed".into()))),
+            None => self
+                .semaphore
+                .acquire()
+                .await
+                .map_err(|_| ZkError::Backend("concurrency guard closed".into())),
+        };
+        self.queue_depth.fetch_sub(1, Ordering::SeqCst);
+        let permit = acquired?;
+        self.active_tasks.fetch_add(1, Ordering::SeqCst);
+        Ok(ConcurrencyPermit { _permit: permit, guard: self })
+    }
+
+    /// Acquire a permit only if one is immediately available, failing fast with
+    /// `ZkError::ResourceLimit` otherwise. For latency-sensitive callers that would rather error
+    /// than queue.
+    pub fn try_acquire(&self) -> ZkResult<ConcurrencyPermit<'_>> {
+        let permit = self
+            .semaphore
+            .try_acquire()
+            .map_err(|_| ZkError::ResourceLimit("no concurrency permit available".into()))?;
+        self.active_tasks.fetch_add(1, Ordering::SeqCst);
+        Ok(ConcurrencyPermit { _permit: permit, guard: self })
+    }
+
+    /// Number of tasks currently holding a permit.
+    pub fn active_tasks(&self) -> usize {
+        self.active_tasks.load(Ordering::SeqCst)
+    }
+
+    /// Number of tasks currently waiting for a permit.
+    pub fn queue_depth(&self) -> usize {
+        self.queue_depth.load(Ordering::SeqCst)
+    }
+
+    /// Write this guard's live `active_tasks`/`queue_depth`/`max_concurrent` into a
+    /// `ResourceUsage` snapshot, so health reporting reflects real backpressure. Intended to be
+    /// called from a backend's `ZkBackend::resource_usage` implementation.
+    pub fn snapshot(&self, mut usage: ResourceUsage) -> ResourceUsage {
+        usage.active_tasks = self.active_tasks();
+        usage.queue_depth = self.queue_depth();
+        usage.max_concurrent = self.max_concurrent;
+        usage
+    }
+}
+
+/// A held admission slot. Releases the slot and decrements `active_tasks` on drop.
+pub struct ConcurrencyPermit<'a> {
+    _permit: SemaphorePermit<'a>,
+    guard: &'a ConcurrencyGuard,
+}
+
+impl Drop for ConcurrencyPermit<'_> {
+    fn drop(&mut self) {
+        self.guard.active_tasks.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn try_acquire_fails_fast_when_exhausted() {
+        let guard = ConcurrencyGuard::new(1);
+        let _first = guard.try_acquire().unwrap();
+        assert!(guard.try_acquire().is_err());
+    }
+
+    #[tokio::test]
+    async fn permit_release_frees_capacity() {
+        let guard = ConcurrencyGuard::new(1);
+        {
+            let _permit = guard.try_acquire().unwrap();
+            assert_eq!(guard.active_tasks(), 1);
+        }
+        assert_eq!(guard.active_tasks(), 0);
+        assert!(guard.try_acquire().is_ok());
+    }
+
+    #[tokio::test]
+    async fn acquire_times_out_when_no_permit_frees_up() {
+        let guard = ConcurrencyGuard::new(1);
+        let _held = guard.try_acquire().unwrap();
+        let result = guard.acquire(Some(Duration::from_millis(10))).await;
+        assert!(matches!(result, Err(ZkError::Timeout(_))));
+    }
+}
@@ -7,8 +7,10 @@ use async_trait::async_trait;
 use std::fmt::Debug;
 
 use crate::{
+    admission::ConcurrencyGuard,
+    da::{ProofHandle, ProofStore},
     error::{ZkError, ZkResult},
-    types::{HealthStatus, ProofMetadata, ResourceUsage, ZkConfig, ZkStats},
+    types::{BatchStats, HealthStatus, ProofMetadata, ResourceUsage, ZkConfig, ZkStats},
 };
 
 /// Core trait for ZK backend implementations
@@ -57,23 +59,253 @@ pub trait ZkBackend: Send + Sync + Debug {
 /// Extension trait for advanced ZK backend features
 #[async_trait]
 pub trait ZkBackendExt: ZkBackend {
-    /// Generate proofs for multiple programs in batch
+    /// Generate proofs for multiple programs in batch.
+    ///
+    /// The default implementation simply maps over [`ZkBackend::prove`] one item at a time, so
+    /// existing backends keep compiling without change. Backends that can amortize fixed proving
+    /// costs (shared witness generation, a single setup pass, etc.) should override this to prove
+    /// the batch together. Batches larger than `config.max_batch_size` (when set) are rejected
+    /// up front with `ZkError::ResourceLimit` rather than attempted.
     async fn batch_prove(
         &self,
         programs: &[(&[u8], &[u8])],
         config: Option<&ZkConfig>,
-    ) -> ZkResult<Vec<(Vec<u8>, ProofMetadata)>>;
+    ) -> ZkResult<Vec<(Vec<u8>, ProofMetadata)>> {
+        check_batch_size(programs.len(), config)?;
+        let mut results = Vec::with_capacity(programs.len());
+        for (program, input) in programs {
+            results.push(self.prove(program, input, config).await?);
+        }
+        Ok(results)
+    }
 
-    /// Verify multiple proofs in batch
+    /// Verify multiple proofs in batch.
+    ///
+    /// The default implementation simply maps over [`ZkBackend::verify`] one item at a time, so
+    /// existing backends keep compiling without change. Backends that can share verification
+    /// setup across items should override this. Batches larger than `config.max_batch_size`
+    /// (when set) are rejected up front with `ZkError::ResourceLimit` rather than attempted.
     async fn batch_verify(
         &self,
         verifications: &[(&[u8], &[u8])],
         config: Option<&ZkConfig>,
-    ) -> ZkResult<Vec<bool>>;
+    ) -> ZkResult<Vec<bool>> {
+        check_batch_size(verifications.len(), config)?;
+        let mut results = Vec::with_capacity(verifications.len());
+        for (program, proof) in verifications {
+            results.push(self.verify(program, proof, config).await?);
+        }
+        Ok(results)
+    }
+
+    /// Statistics for the most recently completed batch operation, if any.
+    fn batch_stats(&self) -> Option<BatchStats> {
+        None
+    }
+
+    /// The admission-control guard enforcing this backend's concurrency limits, if any.
+    ///
+    /// `None` (the default) means `prove`/`verify` are never gated — today's unbounded behavior.
+    /// Backends that want to enforce a limit on concurrent tasks should hold a
+    /// [`ConcurrencyGuard`] and return it here; [`ZkBackendExt::prove_admitted`] and
+    /// [`ZkBackendExt::verify_admitted`] will then wait for a permit before calling through.
+    fn admission_guard(&self) -> Option<&ConcurrencyGuard> {
+        None
+    }
+
+    /// Prove subject to this backend's admission-control policy, if it has one.
+    ///
+    /// Waits for a permit (bounded by `config.proving_timeout`) before delegating to
+    /// [`ZkBackend::prove`]. Falls through to an unguarded call when `admission_guard` is `None`.
+    async fn prove_admitted(
+        &self,
+        program: &[u8],
+        input: &[u8],
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<(Vec<u8>, ProofMetadata)> {
+        match self.admission_guard() {
+            Some(guard) => {
+                let _permit = guard
+                    .acquire(config.and_then(|c| c.proving_timeout))
+                    .await?;
+                self.prove(program, input, config).await
+            }
+            None => self.prove(program, input, config).await,
+        }
+    }
+
+    /// Verify subject to this backend's admission-control policy, if it has one.
+    ///
+    /// Waits for a permit (bounded by `config.verification_timeout`) before delegating to
+    /// [`ZkBackend::verify`]. Falls through to an unguarded call when `admission_guard` is `None`.
+    async fn verify_admitted(
+        &self,
+        program: &[u8],
+        proof: &[u8],
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<bool> {
+        match self.admission_guard() {
+            Some(guard) => {
+                let _permit = guard
+                    .acquire(config.and_then(|c| c.verification_timeout))
+                    .await?;
+                self.verify(program, proof, config).await
+            }
+            None => self.verify(program, proof, config).await,
+        }
+    }
+
+    /// Generate a proof and dispatch it to `store`, returning the resulting handle alongside the
+    /// proof's metadata.
+    ///
+    /// This is the integration point for decoupling "where the proof is computed" from "where
+    /// the proof bytes live" that today's `prove`/`verify` signatures (raw bytes in/out) can't
+    /// express on their own.
+    async fn prove_and_store(
+        &self,
+        program: &[u8],
+        input: &[u8],
+        store: &dyn ProofStore,
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<(ProofHandle, ProofMetadata)> {
+        let (proof, metadata) = self.prove(program, input, config).await?;
+        let handle = store.put(&metadata, &proof).await?;
+        Ok((handle, metadata))
+    }
 
     /// Clear any cached data
     async fn clear_cache(&mut self) -> ZkResult<()>;
 
     /// Get backend-specific capabilities
     fn capabilities(&self) -> Vec<String>;
-} 
\ No newline at end of file
+}
+
+/// Reject batches larger than `config.max_batch_size`, when that limit is set.
+fn check_batch_size(batch_len: usize, config: Option<&ZkConfig>) -> ZkResult<()> {
+    if let Some(max) = config.and_then(|c| c.max_batch_size) {
+        if batch_len > max {
+            return Err(ZkError::ResourceLimit(format!(
+                "batch size {batch_len} exceeds max_batch_size {max}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::Clock;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// [`ZkBackend`] that echoes each input as its proof and counts how many times
+    /// `prove`/`verify` were called, to exercise [`ZkBackendExt`]'s default batch fan-out.
+    #[derive(Debug, Default)]
+    struct MockBackend {
+        prove_calls: AtomicUsize,
+        verify_calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ZkBackend for MockBackend {
+        async fn prove(
+            &self,
+            program: &[u8],
+            input: &[u8],
+            _config: Option<&ZkConfig>,
+        ) -> ZkResult<(Vec<u8>, ProofMetadata)> {
+            self.prove_calls.fetch_add(1, Ordering::SeqCst);
+            Ok((
+                input.to_vec(),
+                ProofMetadata {
+                    generation_time: Duration::default(),
+                    proof_size: input.len(),
+                    program_hash: format!("{program:x?}"),
+                    timestamp: crate::clock::default_clock().now(),
+                    codec_id: 0,
+                    compressed_size: None,
+                },
+            ))
+        }
+
+        async fn verify(
+            &self,
+            program: &[u8],
+            proof: &[u8],
+            _config: Option<&ZkConfig>,
+        ) -> ZkResult<bool> {
+            self.verify_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(proof == program)
+        }
+
+        fn resource_usage(&self) -> ResourceUsage {
+            ResourceUsage {
+                cpu_usage: 0.0,
+                memory_usage: 0,
+                active_tasks: 0,
+                max_concurrent: 1,
+                queue_depth: 0,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ZkBackendExt for MockBackend {
+        async fn clear_cache(&mut self) -> ZkResult<()> {
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Vec<String> {
+            Vec::new()
+        }
+    }
+
+    fn config_with_max_batch_size(max_batch_size: Option<usize>) -> ZkConfig {
+        ZkConfig {
+            max_program_size: None,
+            max_input_size: None,
+            proving_timeout: None,
+            verification_timeout: None,
+            max_memory: None,
+            proof_codec: None,
+            max_batch_size,
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_prove_calls_prove_once_per_item_in_order() {
+        let backend = MockBackend::default();
+        let programs: Vec<(&[u8], &[u8])> = vec![(b"prog-a", b"in-a"), (b"prog-b", b"in-b")];
+
+        let results = backend.batch_prove(&programs, None).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, b"in-a".to_vec());
+        assert_eq!(results[1].0, b"in-b".to_vec());
+        assert_eq!(backend.prove_calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn batch_verify_calls_verify_once_per_item_in_order() {
+        let backend = MockBackend::default();
+        let verifications: Vec<(&[u8], &[u8])> =
+            vec![(b"prog-a", b"prog-a"), (b"prog-b", b"mismatch")];
+
+        let results = backend.batch_verify(&verifications, None).await.unwrap();
+
+        assert_eq!(results, vec![true, false]);
+        assert_eq!(backend.verify_calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn batch_prove_rejects_batch_exceeding_max_batch_size() {
+        let backend = MockBackend::default();
+        let programs: Vec<(&[u8], &[u8])> = vec![(b"prog-a", b"in-a"), (b"prog-b", b"in-b")];
+        let config = config_with_max_batch_size(Some(1));
+
+        let err = backend.batch_prove(&programs, Some(&config)).await.unwrap_err();
+        assert!(matches!(err, ZkError::ResourceLimit(_)));
+        assert_eq!(backend.prove_calls.load(Ordering::SeqCst), 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+//! Clock abstraction for `no_std` targets
+//!
+//! Guest zkVM environments and other constrained `no_std` targets don't have a wall clock, so
+//! [`crate::types::ProofMetadata`] and [`crate::error::ErrorContext`] obtain timestamps through
+//! the [`Clock`] trait instead of calling `std::time::SystemTime::now()` directly. The `std`
+//! feature supplies [`SystemClock`], a thin wrapper over the system clock; `no_std` builds must
+//! supply their own (a monotonic tick counter) or fall back to [`NullClock`] when timestamps
+//! aren't meaningful in that environment.
+
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use core::time::Duration;
+
+/// A point in time, expressed as a duration since an implementation-defined epoch.
+///
+/// Under `std` the epoch is the Unix epoch; under `no_std` it's whatever the supplied [`Clock`]
+/// chooses (e.g. "time since guest execution started").
+pub type Timestamp = Duration;
+
+/// Supplies the current time to code that needs to timestamp events without depending on `std`.
+pub trait Clock: Send + Sync {
+    /// Return the current time as a [`Timestamp`].
+    fn now(&self) -> Timestamp;
+}
+
+/// [`Clock`] backed by the system's wall clock. Only available with the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> Timestamp {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
+
+/// [`Clock`] that always returns [`Duration::ZERO`].
+///
+/// Useful as a placeholder in `no_std` environments with no notion of wall-clock time: timestamps
+/// aren't meaningful there, but a `Clock` impl is still required to construct metadata.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NullClock;
+
+impl Clock for NullClock {
+    fn now(&self) -> Timestamp {
+        Duration::ZERO
+    }
+}
+
+/// The default [`Clock`] for this build: [`SystemClock`] under `std`, [`NullClock`] otherwise.
+#[cfg(feature = "std")]
+pub fn default_clock() -> SystemClock {
+    SystemClock
+}
+
+/// The default [`Clock`] for this build: [`SystemClock`] under `std`, [`NullClock`] otherwise.
+#[cfg(not(feature = "std"))]
+pub fn default_clock() -> NullClock {
+    NullClock
+}
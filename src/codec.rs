@@ -0,0 +1,272 @@
+//! Proof compression codecs
+//!
+//! Proof bytes dominate on-chain/storage cost for most backends, so this module lets
+//! [`crate::backend::ZkBackend`] implementors optionally compress proofs before returning them
+//! from `prove` and transparently decompress them before `verify`. Compressed proofs are framed
+//! with a small header so any codec can be identified and the original size recovered without
+//! re-scanning the stream.
+
+#[cfg(feature = "std")]
+use flate2::read::DeflateDecoder;
+#[cfg(feature = "std")]
+use flate2::write::DeflateEncoder;
+#[cfg(feature = "std")]
+use flate2::Compression;
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+use crate::error::{ZkError, ZkResult};
+
+/// Identifies which [`ProofCodec`] was used to compress a proof.
+///
+/// The discriminant values double as the on-wire codec id stored in the frame header, so they
+/// must never be reassigned once shipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[repr(u8)]
+pub enum CodecKind {
+    /// No compression; proof bytes are passed through unchanged.
+    None = 0,
+    /// DEFLATE (RFC 1951) via `flate2`.
+    Deflate = 1,
+}
+
+impl CodecKind {
+    /// Recover a `CodecKind` from its on-wire id.
+    pub fn from_id(id: u8) -> ZkResult<Self> {
+        match id {
+            0 => Ok(CodecKind::None),
+            1 => Ok(CodecKind::Deflate),
+            other => Err(ZkError::Serialization(format!("unknown codec id: {other}"))),
+        }
+    }
+
+    /// The on-wire id for this codec.
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Compresses and decompresses proof bytes for transport/storage.
+///
+/// Implementations own their framing: `compress` must prefix the compressed stream with a
+/// header that `decompress` can use to recover the exact original length, so callers can
+/// preallocate the output buffer instead of growing it incrementally.
+pub trait ProofCodec: Send + Sync {
+    /// Which codec this implementation identifies itself as in the frame header.
+    fn kind(&self) -> CodecKind;
+
+    /// Compress `data`, returning a self-framed byte stream.
+    fn compress(&self, data: &[u8]) -> ZkResult<Vec<u8>>;
+
+    /// Decompress a byte stream previously produced by [`ProofCodec::compress`].
+    fn decompress(&self, data: &[u8]) -> ZkResult<Vec<u8>>;
+}
+
+/// Default [`ProofCodec`] backed by DEFLATE. Requires the `std` feature (via `flate2`).
+///
+/// Frame layout: a 1-byte codec id, a varint-encoded original (uncompressed) length, then the
+/// raw DEFLATE stream.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeflateCodec {
+    level: Compression,
+}
+
+#[cfg(feature = "std")]
+impl DeflateCodec {
+    /// Create a codec using the default compression level.
+    pub fn new() -> Self {
+        Self { level: Compression::default() }
+    }
+
+    /// Create a codec using a specific compression level (0-9).
+    pub fn with_level(level: u32) -> Self {
+        Self { level: Compression::new(level) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ProofCodec for DeflateCodec {
+    fn kind(&self) -> CodecKind {
+        CodecKind::Deflate
+    }
+
+    fn compress(&self, data: &[u8]) -> ZkResult<Vec<u8>> {
+        let mut encoder = DeflateEncoder::new(Vec::new(), self.level);
+        encoder
+            .write_all(data)
+            .map_err(|e| ZkError::Serialization(format!("deflate compression failed: {e}")))?;
+        let deflated = encoder
+            .finish()
+            .map_err(|e| ZkError::Serialization(format!("deflate compression failed: {e}")))?;
+
+        let mut framed = Vec::with_capacity(1 + 10 + deflated.len());
+        framed.push(self.kind().id());
+        write_varint(data.len() as u64, &mut framed);
+        framed.extend_from_slice(&deflated);
+        Ok(framed)
+    }
+
+    fn decompress(&self, data: &[u8]) -> ZkResult<Vec<u8>> {
+        if data.is_empty() {
+            return Err(ZkError::Serialization("empty compressed proof frame".into()));
+        }
+        let codec_id = CodecKind::from_id(data[0])?;
+        if codec_id != CodecKind::Deflate {
+            return Err(ZkError::Serialization(format!(
+                "codec mismatch: frame declares {codec_id:?}, expected Deflate"
+            )));
+        }
+
+        let (original_len, header_len) = read_varint(&data[1..])
+            .ok_or_else(|| ZkError::Serialization("truncated codec header".into()))?;
+        let body = &data[1 + header_len..];
+
+        let mut out = Vec::with_capacity(original_len as usize);
+        let mut decoder = DeflateDecoder::new(body);
+        decoder
+            .read_to_end(&mut out)
+            .map_err(|e| ZkError::Serialization(format!("deflate decompression failed: {e}")))?;
+
+        if out.len() as u64 != original_len {
+            return Err(ZkError::Serialization(format!(
+                "decompressed length mismatch: header says {original_len}, got {}",
+                out.len()
+            )));
+        }
+        Ok(out)
+    }
+}
+
+/// No-op codec for callers that want the uniform `ProofCodec` interface without compression.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopCodec;
+
+impl ProofCodec for NoopCodec {
+    fn kind(&self) -> CodecKind {
+        CodecKind::None
+    }
+
+    fn compress(&self, data: &[u8]) -> ZkResult<Vec<u8>> {
+        let mut framed = Vec::with_capacity(1 + 10 + data.len());
+        framed.push(self.kind().id());
+        write_varint(data.len() as u64, &mut framed);
+        framed.extend_from_slice(data);
+        Ok(framed)
+    }
+
+    fn decompress(&self, data: &[u8]) -> ZkResult<Vec<u8>> {
+        if data.is_empty() {
+            return Err(ZkError::Serialization("empty codec frame".into()));
+        }
+        let codec_id = CodecKind::from_id(data[0])?;
+        if codec_id != CodecKind::None {
+            return Err(ZkError::Serialization(format!(
+                "codec mismatch: frame declares {codec_id:?}, expected None"
+            )));
+        }
+
+        let (original_len, header_len) = read_varint(&data[1..])
+            .ok_or_else(|| ZkError::Serialization("truncated codec header".into()))?;
+        let body = &data[1 + header_len..];
+        if body.len() as u64 != original_len {
+            return Err(ZkError::Serialization(format!(
+                "length mismatch: header says {original_len}, got {}",
+                body.len()
+            )));
+        }
+        Ok(body.to_vec())
+    }
+}
+
+/// Resolve the default codec implementation for a [`CodecKind`]. Requires `std`, since
+/// [`CodecKind::Deflate`] only has an implementation under that feature.
+#[cfg(feature = "std")]
+pub fn codec_for(kind: CodecKind) -> Box<dyn ProofCodec> {
+    match kind {
+        CodecKind::None => Box::new(NoopCodec),
+        CodecKind::Deflate => Box::new(DeflateCodec::new()),
+    }
+}
+
+fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn read_varint(data: &[u8]) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    for (i, &byte) in data.iter().enumerate() {
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+        shift += 7;
+        if shift >= 64 {
+            return None;
+        }
+    }
+    None
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deflate_roundtrip() {
+        let codec = DeflateCodec::new();
+        let data = b"the quick brown fox jumps over the lazy dog".repeat(16);
+        let compressed = codec.compress(&data).unwrap();
+        let decompressed = codec.decompress(&compressed).unwrap();
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn noop_roundtrip() {
+        let codec = NoopCodec;
+        let data = b"arbitrary proof bytes".to_vec();
+        let compressed = codec.compress(&data).unwrap();
+        let decompressed = codec.decompress(&compressed).unwrap();
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn varint_roundtrip() {
+        for value in [0u64, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            let mut buf = Vec::new();
+            write_varint(value, &mut buf);
+            let (decoded, len) = read_varint(&buf).unwrap();
+            assert_eq!(decoded, value);
+            assert_eq!(len, buf.len());
+        }
+    }
+
+    #[test]
+    fn noop_decompress_rejects_codec_mismatch() {
+        let codec = NoopCodec;
+        let mut framed = codec.compress(b"hello world").unwrap();
+        framed[0] = CodecKind::Deflate.id();
+        assert!(codec.decompress(&framed).is_err());
+    }
+
+    #[test]
+    fn decompress_rejects_length_mismatch() {
+        let codec = DeflateCodec::new();
+        let mut framed = codec.compress(b"hello world").unwrap();
+        // Corrupt the header length without touching the compressed body.
+        framed[1] = 0x7f;
+        assert!(codec.decompress(&framed).is_err());
+    }
+}
@@ -0,0 +1,225 @@
+//! Evaluation-point proof interface for polynomial-commitment backends
+//!
+//! [`crate::backend::ZkBackend`]'s `prove`/`verify` only model "program + input → proof", which
+//! can't express polynomial-commitment schemes (KZG and friends): commit to data once, then
+//! later prove an evaluation `f(z) = y` of the committed polynomial against that commitment.
+//! [`CommitmentBackend`] models that workflow directly so PCS backends can plug into Frostgate
+//! alongside the existing SNARK/STARK `ZkBackend` implementors.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+use crate::error::{ZkError, ZkResult};
+
+/// A commitment to some underlying data (e.g. a polynomial or a blob), as opaque bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Commitment(pub Vec<u8>);
+
+/// A proof that a committed value opens to a claimed [`Evaluation`] at a point, as opaque bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpeningProof(pub Vec<u8>);
+
+/// The claimed value `y` of a committed polynomial `f` at a point `z`, i.e. `f(z) = y`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Evaluation(pub Vec<u8>);
+
+/// Core trait for polynomial-commitment-style backends (KZG and similar schemes).
+#[async_trait]
+pub trait CommitmentBackend: Send + Sync + Debug {
+    /// Commit to `data`, producing a succinct [`Commitment`].
+    async fn commit(&self, data: &[u8]) -> ZkResult<Commitment>;
+
+    /// Open the commitment to `data` at evaluation point `z`, returning both the claimed
+    /// evaluation `y = f(z)` and a proof that the opening is consistent with the commitment.
+    async fn open(&self, data: &[u8], z: &[u8]) -> ZkResult<(OpeningProof, Evaluation)>;
+
+    /// Verify that `commitment` opens to `y` at point `z`, per `proof`.
+    async fn verify_opening(
+        &self,
+        commitment: &Commitment,
+        z: &[u8],
+        y: &Evaluation,
+        proof: &OpeningProof,
+    ) -> ZkResult<bool>;
+
+    /// Open `data` at multiple evaluation points, returning a single aggregated proof covering
+    /// all of them plus the corresponding evaluations (one per point, same order as `points`).
+    ///
+    /// The default implementation calls [`CommitmentBackend::open`] once per point and
+    /// concatenates the individual proofs behind a 4-byte big-endian length prefix per proof, so
+    /// [`CommitmentBackend::verify_batch_opening`]'s default can split them back apart; backends
+    /// without a batched opening scheme keep compiling. Backends that support a real aggregated
+    /// multi-point opening (mirroring how blob-commitment systems batch openings) should override
+    /// both methods together.
+    async fn open_batch(
+        &self,
+        data: &[u8],
+        points: &[Vec<u8>],
+    ) -> ZkResult<(OpeningProof, Vec<Evaluation>)> {
+        let mut proof_bytes = Vec::new();
+        let mut evaluations = Vec::with_capacity(points.len());
+        for z in points {
+            let (proof, y) = self.open(data, z).await?;
+            proof_bytes.extend_from_slice(&(proof.0.len() as u32).to_be_bytes());
+            proof_bytes.extend_from_slice(&proof.0);
+            evaluations.push(y);
+        }
+        Ok((OpeningProof(proof_bytes), evaluations))
+    }
+
+    /// Verify a batch opening produced by [`CommitmentBackend::open_batch`]: that `commitment`
+    /// opens to each of `evaluations` at the corresponding `points`, per `proof`.
+    ///
+    /// The default implementation splits `proof` back into its per-point components (per the
+    /// length-prefixed framing [`CommitmentBackend::open_batch`]'s default writes) and calls
+    /// [`CommitmentBackend::verify_opening`] once per point. Backends that override `open_batch`
+    /// with a real aggregated scheme should override this too.
+    async fn verify_batch_opening(
+        &self,
+        commitment: &Commitment,
+        points: &[Vec<u8>],
+        evaluations: &[Evaluation],
+        proof: &OpeningProof,
+    ) -> ZkResult<bool> {
+        if points.len() != evaluations.len() {
+            return Err(ZkError::Input(format!(
+                "verify_batch_opening: {} points but {} evaluations",
+                points.len(),
+                evaluations.len()
+            )));
+        }
+        let components = split_batch_proof(&proof.0)?;
+        if components.len() != points.len() {
+            return Err(ZkError::VerificationFailed(format!(
+                "batch opening proof has {} component(s), expected {}",
+                components.len(),
+                points.len()
+            )));
+        }
+        for ((z, y), component) in points.iter().zip(evaluations).zip(components) {
+            let opening_proof = OpeningProof(component.to_vec());
+            if !self.verify_opening(commitment, z, y, &opening_proof).await? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Split a length-prefixed batch opening proof (as produced by
+/// [`CommitmentBackend::open_batch`]'s default implementation) back into its per-point proof
+/// byte slices, in order.
+fn split_batch_proof(bytes: &[u8]) -> ZkResult<Vec<&[u8]>> {
+    let mut components = Vec::new();
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        if rest.len() < 4 {
+            return Err(ZkError::Serialization(
+                "truncated batch opening proof: missing length prefix".to_string(),
+            ));
+        }
+        let (len_bytes, tail) = rest.split_at(4);
+        let len = u32::from_be_bytes(len_bytes.try_into().unwrap()) as usize;
+        if tail.len() < len {
+            return Err(ZkError::Serialization(
+                "truncated batch opening proof: component shorter than its declared length"
+                    .to_string(),
+            ));
+        }
+        let (component, tail) = tail.split_at(len);
+        components.push(component);
+        rest = tail;
+    }
+    Ok(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Toy backend: "commits" to the whole blob and "opens" at `z` by indexing into it
+    /// (`z[0] % data.len()`), with the opening proof just being that index. Good enough to
+    /// exercise the default `open_batch`/`verify_batch_opening` framing without real crypto.
+    #[derive(Debug)]
+    struct MockBackend;
+
+    #[async_trait]
+    impl CommitmentBackend for MockBackend {
+        async fn commit(&self, data: &[u8]) -> ZkResult<Commitment> {
+            Ok(Commitment(data.to_vec()))
+        }
+
+        async fn open(&self, data: &[u8], z: &[u8]) -> ZkResult<(OpeningProof, Evaluation)> {
+            let index = z[0] as usize % data.len();
+            Ok((OpeningProof(vec![index as u8]), Evaluation(vec![data[index]])))
+        }
+
+        async fn verify_opening(
+            &self,
+            commitment: &Commitment,
+            z: &[u8],
+            y: &Evaluation,
+            proof: &OpeningProof,
+        ) -> ZkResult<bool> {
+            let index = z[0] as usize % commitment.0.len();
+            Ok(proof.0 == [index as u8] && commitment.0[index] == y.0[0])
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_opening_round_trips_through_default_impls() {
+        let backend = MockBackend;
+        let data = b"frostgate-zkip-commitment".to_vec();
+        let commitment = backend.commit(&data).await.unwrap();
+        let points = vec![vec![2u8], vec![7u8], vec![15u8]];
+
+        let (proof, evaluations) = backend.open_batch(&data, &points).await.unwrap();
+        assert_eq!(evaluations.len(), points.len());
+
+        let valid = backend
+            .verify_batch_opening(&commitment, &points, &evaluations, &proof)
+            .await
+            .unwrap();
+        assert!(valid);
+    }
+
+    #[tokio::test]
+    async fn batch_opening_rejects_tampered_evaluation() {
+        let backend = MockBackend;
+        let data = b"frostgate-zkip-commitment".to_vec();
+        let commitment = backend.commit(&data).await.unwrap();
+        let points = vec![vec![2u8], vec![7u8]];
+
+        let (proof, mut evaluations) = backend.open_batch(&data, &points).await.unwrap();
+        evaluations[0] = Evaluation(vec![evaluations[0].0[0].wrapping_add(1)]);
+
+        let valid = backend
+            .verify_batch_opening(&commitment, &points, &evaluations, &proof)
+            .await
+            .unwrap();
+        assert!(!valid);
+    }
+
+    #[tokio::test]
+    async fn split_batch_proof_rejects_truncated_input() {
+        let err = split_batch_proof(&[0, 0, 0, 5, 1, 2]).unwrap_err();
+        assert!(matches!(err, ZkError::Serialization(_)));
+    }
+
+    #[tokio::test]
+    async fn verify_batch_opening_rejects_mismatched_point_count() {
+        let backend = MockBackend;
+        let data = b"frostgate-zkip-commitment".to_vec();
+        let commitment = backend.commit(&data).await.unwrap();
+        let points = vec![vec![2u8], vec![7u8]];
+
+        let (proof, evaluations) = backend.open_batch(&data, &points).await.unwrap();
+
+        let err = backend
+            .verify_batch_opening(&commitment, &points[..1], &evaluations, &proof)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ZkError::Input(_)));
+    }
+}
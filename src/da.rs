@@ -0,0 +1,134 @@
+//! Pluggable data-availability / proof-store backends
+//!
+//! ZKIP's `prove`/`verify` signatures are "raw bytes in, raw bytes out" and say nothing about
+//! where proof bytes end up living once generated. Large deployments decouple "where the proof
+//! is computed" from "where the proof bytes live" (S3, IPFS, an on-chain blob, ...), selecting a
+//! DA target at runtime. This module defines [`ProofStore`], the trait such targets implement,
+//! along with [`ProofHandle`], a content-addressed reference to a stored proof.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Mutex;
+
+use crate::error::{ZkError, ZkResult};
+use crate::types::ProofMetadata;
+
+/// A content-addressed reference to a proof stored in a [`ProofStore`].
+///
+/// `hash` is the sha256 of the proof bytes and is always present; `locator` is an optional
+/// store-specific string (an S3 key, an IPFS CID, an on-chain transaction hash, ...) that
+/// implementations may use to speed up `get` without having to reverse a content hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofHandle {
+    /// sha256 of the proof bytes, hex-encoded
+    pub hash: String,
+    /// Store-specific locator (object key, CID, tx hash, ...), if the store has one
+    pub locator: Option<String>,
+}
+
+impl ProofHandle {
+    /// Compute the content-addressed handle for a proof, without a locator.
+    pub fn for_proof(proof: &[u8]) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(proof);
+        Self {
+            hash: format!("{:x}", hasher.finalize()),
+            locator: None,
+        }
+    }
+
+    /// The same handle with a store-specific locator attached.
+    pub fn with_locator(mut self, locator: impl Into<String>) -> Self {
+        self.locator = Some(locator.into());
+        self
+    }
+}
+
+/// Stores and retrieves proof bytes, decoupled from where/how they were generated.
+///
+/// Implementations are expected to be cheap to clone/share (wrap internal state in an `Arc` if
+/// needed) since a single store is typically handed to many `prove_and_store` calls.
+#[async_trait]
+pub trait ProofStore: Send + Sync + Debug {
+    /// Persist `proof`'s bytes alongside its metadata, returning a handle that can later be
+    /// passed to [`ProofStore::get`].
+    async fn put(&self, metadata: &ProofMetadata, proof: &[u8]) -> ZkResult<ProofHandle>;
+
+    /// Retrieve the proof bytes previously stored under `handle`.
+    async fn get(&self, handle: &ProofHandle) -> ZkResult<Vec<u8>>;
+}
+
+/// In-memory [`ProofStore`] for tests and local development.
+///
+/// Proofs are lost when the store is dropped; production deployments should provide an
+/// S3/IPFS/on-chain-backed implementation instead.
+#[derive(Debug, Default)]
+pub struct InMemoryProofStore {
+    proofs: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryProofStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ProofStore for InMemoryProofStore {
+    async fn put(&self, _metadata: &ProofMetadata, proof: &[u8]) -> ZkResult<ProofHandle> {
+        let handle = ProofHandle::for_proof(proof);
+        self.proofs
+            .lock()
+            .map_err(|_| ZkError::Backend("in-memory proof store lock poisoned".into()))?
+            .insert(handle.hash.clone(), proof.to_vec());
+        Ok(handle)
+    }
+
+    async fn get(&self, handle: &ProofHandle) -> ZkResult<Vec<u8>> {
+        self.proofs
+            .lock()
+            .map_err(|_| ZkError::Backend("in-memory proof store lock poisoned".into()))?
+            .get(&handle.hash)
+            .cloned()
+            .ok_or_else(|| {
+                ZkError::Backend(format!("no proof stored for handle {}", handle.hash).into())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::Clock;
+
+    fn sample_metadata() -> ProofMetadata {
+        ProofMetadata {
+            generation_time: std::time::Duration::from_millis(1),
+            proof_size: 4,
+            program_hash: "deadbeef".into(),
+            timestamp: crate::clock::default_clock().now(),
+            codec_id: 0,
+            compressed_size: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn put_then_get_roundtrips() {
+        let store = InMemoryProofStore::new();
+        let proof = b"proof-bytes".to_vec();
+        let handle = store.put(&sample_metadata(), &proof).await.unwrap();
+        let fetched = store.get(&handle).await.unwrap();
+        assert_eq!(fetched, proof);
+    }
+
+    #[tokio::test]
+    async fn get_missing_handle_errors() {
+        let store = InMemoryProofStore::new();
+        let handle = ProofHandle::for_proof(b"never-stored");
+        assert!(store.get(&handle).await.is_err());
+    }
+}
@@ -1,8 +1,24 @@
 //! Error types for the ZK backend interface
 
-use std::fmt;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+
+use core::fmt;
 use thiserror::Error;
 
+use crate::clock::{Clock, Timestamp};
+
+/// Map type for [`ErrorContext::details`]. A real `HashMap` under `std`; a `BTreeMap` under
+/// `no_std` since we'd otherwise need to pull in a hasher crate for no real benefit at the sizes
+/// this map is used at.
+#[cfg(feature = "std")]
+pub type DetailsMap = std::collections::HashMap<String, String>;
+/// Map type for [`ErrorContext::details`]. A real `HashMap` under `std`; a `BTreeMap` under
+/// `no_std` since we'd otherwise need to pull in a hasher crate for no real benefit at the sizes
+/// this map is used at.
+#[cfg(not(feature = "std"))]
+pub type DetailsMap = alloc::collections::BTreeMap<String, String>;
+
 /// Result type for ZK operations
 pub type ZkResult<T> = Result<T, ZkError>;
 
@@ -37,13 +53,64 @@ pub enum ZkError {
     #[error("Serialization error: {0}")]
     Serialization(String),
 
-    /// Backend-specific error
+    /// Backend-specific error. Boxed as a trait object (rather than a `String`) so backend
+    /// implementations can attach their own concrete error type and have callers downcast it
+    /// back out via [`ZkError::backend_error`]; `String`/`&str` still convert via `.into()`
+    /// thanks to the blanket `From` impls for `Box<dyn Error + Send + Sync>`. Uses
+    /// `core::error::Error` (not `std::error::Error`) so this variant, and `backend_error()`
+    /// below, stay available under `no_std` too.
     #[error("Backend error: {0}")]
-    Backend(String),
+    Backend(#[source] Box<dyn core::error::Error + Send + Sync>),
 
     /// Configuration error
     #[error("Configuration error: {0}")]
     Config(String),
+
+    /// An error with one or more [`ErrorContext`] frames attached, most recent last. Produced by
+    /// [`ZkError::with_operation`] as an error propagates up through layers; `Display` stays
+    /// compact (it defers to the wrapped error), use [`ZkError::chain`] to see the full context.
+    #[error("{0}")]
+    Contextual(Box<ZkError>, Vec<ErrorContext>),
+}
+
+impl ZkError {
+    /// Attach an [`ErrorContext`] frame recording `operation`, returning an error that carries
+    /// the full context chain inside itself (rather than as a sibling tuple). Calling this
+    /// repeatedly as an error propagates up through layers builds up a chain in call order.
+    pub fn with_operation(self, operation: &str) -> ZkError {
+        let frame = ErrorContext::capture(operation);
+        match self {
+            ZkError::Contextual(inner, mut chain) => {
+                chain.push(frame);
+                ZkError::Contextual(inner, chain)
+            }
+            other => ZkError::Contextual(Box::new(other), vec![frame]),
+        }
+    }
+
+    /// The [`ErrorContext`] frames attached to this error, oldest first. Empty for errors that
+    /// never went through [`ZkError::with_operation`].
+    pub fn contexts(&self) -> &[ErrorContext] {
+        match self {
+            ZkError::Contextual(_, chain) => chain.as_slice(),
+            _ => &[],
+        }
+    }
+
+    /// Iterate over the [`ErrorContext`] frames attached to this error, oldest first.
+    pub fn chain(&self) -> core::slice::Iter<'_, ErrorContext> {
+        self.contexts().iter()
+    }
+
+    /// Borrow the underlying backend error for downcasting, if this is a `Backend` error (or a
+    /// `Contextual` wrapper around one).
+    pub fn backend_error(&self) -> Option<&(dyn core::error::Error + Send + Sync + 'static)> {
+        match self {
+            ZkError::Backend(e) => Some(e.as_ref()),
+            ZkError::Contextual(inner, _) => inner.backend_error(),
+            _ => None,
+        }
+    }
 }
 
 /// Error context for additional information
@@ -51,10 +118,30 @@ pub enum ZkError {
 pub struct ErrorContext {
     /// Operation being performed when error occurred
     pub operation: String,
-    /// Time when error occurred
-    pub timestamp: std::time::SystemTime,
+    /// Time when error occurred. See [`crate::clock::Clock`] for how this is populated under
+    /// `no_std`.
+    pub timestamp: Timestamp,
     /// Additional context-specific information
-    pub details: std::collections::HashMap<String, String>,
+    pub details: DetailsMap,
+    /// Backtrace captured at the point this context frame was recorded. [`ErrorContext::capture`]
+    /// always sets it; when `RUST_LIB_BACKTRACE`/`RUST_BACKTRACE` isn't enabled the captured
+    /// backtrace is a disabled one (see `Backtrace::status`). Only present under `std`.
+    #[cfg(feature = "std")]
+    pub backtrace: Option<std::sync::Arc<std::backtrace::Backtrace>>,
+}
+
+impl ErrorContext {
+    /// Capture a context frame for `operation` at the current point in the call stack, including
+    /// a backtrace under `std` (subject to `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE`).
+    pub fn capture(operation: impl Into<String>) -> Self {
+        Self {
+            operation: operation.into(),
+            timestamp: crate::clock::default_clock().now(),
+            details: DetailsMap::new(),
+            #[cfg(feature = "std")]
+            backtrace: Some(std::sync::Arc::new(std::backtrace::Backtrace::capture())),
+        }
+    }
 }
 
 impl fmt::Display for ErrorContext {
@@ -65,6 +152,71 @@ impl fmt::Display for ErrorContext {
 
 /// Extension trait for adding context to errors
 pub trait ErrorExt<T> {
-    /// Add context to an error
-    fn with_context(self, operation: impl Into<String>) -> Result<T, (ZkError, ErrorContext)>;
-} 
\ No newline at end of file
+    /// Add context to an error, returning a `ZkError` with the operation name (and, under
+    /// `std`, a backtrace) folded into the error's own context chain rather than a sibling
+    /// tuple. See [`ZkError::with_operation`].
+    fn with_context(self, operation: impl Into<String>) -> ZkResult<T>;
+}
+
+impl<T> ErrorExt<T> for ZkResult<T> {
+    fn with_context(self, operation: impl Into<String>) -> ZkResult<T> {
+        self.map_err(|e| e.with_operation(&operation.into()))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct CustomBackendError(String);
+
+    impl fmt::Display for CustomBackendError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "custom backend error: {}", self.0)
+        }
+    }
+
+    impl std::error::Error for CustomBackendError {}
+
+    #[test]
+    fn with_operation_builds_and_extends_context_chain() {
+        let err = ZkError::Input("bad input".to_string())
+            .with_operation("parse")
+            .with_operation("validate");
+
+        let operations: Vec<&str> = err.chain().map(|ctx| ctx.operation.as_str()).collect();
+        assert_eq!(operations, vec!["parse", "validate"]);
+        assert_eq!(err.contexts().len(), 2);
+    }
+
+    #[test]
+    fn contexts_is_empty_without_with_operation() {
+        let err = ZkError::Input("bad input".to_string());
+        assert!(err.contexts().is_empty());
+    }
+
+    #[test]
+    fn backend_error_downcasts_through_contextual_wrapper() {
+        let err = ZkError::Backend(Box::new(CustomBackendError("boom".to_string())))
+            .with_operation("prove");
+
+        let backend_err = err.backend_error().expect("backend error should be present");
+        let downcast = backend_err
+            .downcast_ref::<CustomBackendError>()
+            .expect("should downcast back to CustomBackendError");
+        assert_eq!(downcast.0, "boom");
+    }
+
+    #[test]
+    fn backend_error_is_none_for_non_backend_variants() {
+        let err = ZkError::Input("bad input".to_string());
+        assert!(err.backend_error().is_none());
+    }
+
+    #[test]
+    fn error_context_capture_populates_backtrace() {
+        let ctx = ErrorContext::capture("some-op");
+        assert!(ctx.backtrace.is_some());
+    }
+}
\ No newline at end of file
@@ -19,6 +19,12 @@
 //! - [`ZkConfig`]: Configuration options for ZK backends
 //! - [`ProofMetadata`]: Metadata associated with generated proofs
 //! - [`ResourceUsage`]: Resource tracking and management
+//! - [`codec::ProofCodec`]: Optional compression for proof bytes on the transport path
+//! - [`zkplug::ZkPlug`]: Advanced, aggregation-capable plugin surface for heterogeneous
+//!   multi-backend setups (ZK-VMs, recursive/remote provers); see its module docs for how it
+//!   relates to [`ZkBackend`]
+//! - [`remote::RemoteZkPlug`]: `ZkPlug` over JSON-RPC/HTTP, for offloading proving to an
+//!   out-of-process prover
 //!
 //! ## Example Usage
 //!
@@ -37,7 +43,15 @@
 //!
 //! ## Feature Flags
 //!
-//! - `std`: Enables standard library features (enabled by default)
+//! - `std`: Enables standard library features (enabled by default). With `std` disabled the
+//!   crate builds `no_std` (plus `alloc`), which is what lets it run inside guest zkVM programs
+//!   and other constrained environments; see [`clock::Clock`] for how timestamps work in that
+//!   mode and [`error::ErrorContext`] for how error detail maps are represented. Only
+//!   [`clock`], [`codec`], [`error`], and [`types`] are no_std-compatible so far — every other
+//!   module (`admission`, `backend`, `commitment`, `da`, `remote`, `zkplug`, and
+//!   `legacy_aliases`) leans on `std`/`tokio`/`async_trait` throughout and is gated on this
+//!   feature; growing the no_std surface to cover them is tracked as follow-up work, not
+//!   something this flag already promises.
 //! - Additional features may be provided by specific backend implementations
 //!
 //! ## Version Compatibility
@@ -45,22 +59,60 @@
 //! The current version (1.0.0) maintains backward compatibility while deprecating older interfaces.
 //! See individual component documentation for specific compatibility notes.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+// These lean on `std`/`tokio`/`async_trait` throughout (channels, sockets, thread-pool-backed
+// async runtime, ...) and have never been made no_std-compatible, unlike `clock`/`codec`/
+// `error`/`types` below — gate them so `--no-default-features` actually builds.
+#[cfg(feature = "std")]
+pub mod admission;
+#[cfg(feature = "std")]
 pub mod backend;
+pub mod clock;
+pub mod codec;
+#[cfg(feature = "std")]
+pub mod commitment;
+#[cfg(feature = "std")]
+pub mod da;
 pub mod error;
+#[cfg(feature = "std")]
+pub mod remote;
 pub mod types;
+#[cfg(feature = "std")]
+pub mod zkplug;
 
 // Re-export main components
+#[cfg(feature = "std")]
+pub use admission::{ConcurrencyGuard, ConcurrencyPermit};
+#[cfg(feature = "std")]
 pub use backend::{ZkBackend, ZkBackendExt};
+pub use clock::{Clock, NullClock};
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use codec::{CodecKind, NoopCodec, ProofCodec};
+#[cfg(feature = "std")]
+pub use codec::{codec_for, DeflateCodec};
+#[cfg(feature = "std")]
+pub use commitment::{Commitment, CommitmentBackend, Evaluation, OpeningProof};
+#[cfg(feature = "std")]
+pub use da::{InMemoryProofStore, ProofHandle, ProofStore};
 pub use error::{ErrorContext, ErrorExt, ZkError, ZkResult};
 pub use types::{
     HealthStatus, ProofMetadata, ResourceUsage, ZkConfig, ZkStats,
 };
 
+/// Deprecated aliases kept for the [`ZkBackend`] migration. These point at the simple,
+/// single-backend `ZkBackend`/`ZkConfig` types; they are unrelated to [`zkplug::ZkPlug`], the
+/// (separately maintained) advanced multi-backend plugin trait.
+#[cfg(feature = "std")]
 #[deprecated(
     since = "1.0.0",
     note = "Use ZkBackend trait and ZkConfig instead. This will be removed in 2.0.0"
 )]
-pub mod zkplug {
+pub mod legacy_aliases {
     pub use super::backend::ZkBackend as ZkPlug;
     pub use super::types::ZkConfig as ZkPlugConfig;
 }
@@ -0,0 +1,530 @@
+//! Remote prover backend over JSON-RPC/HTTP
+//!
+//! Lets Frostgate offload heavy GPU/FPGA proving to a remote machine while keeping the same
+//! [`ZkPlug`] trait surface: [`RemoteZkPlug`] forwards `prove`/`verify`/`execute` to an
+//! out-of-process prover over HTTP(S); [`RemoteZkPlugServer`] is the matching server-side harness
+//! that turns any concrete `ZkPlug` implementation into request/response handlers for the same
+//! protocol. Binding those handlers to an actual socket (axum, hyper, warp, ...) is left to the
+//! embedding application — this module only owns the wire format and the trait-facing client.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::zkplug::{
+    BackendInfo, ExecutionResult, HealthStatus, ResourceUsage, ZkCapability, ZkConfig, ZkError,
+    ZkPlug, ZkProof,
+};
+
+/// Wire-format request body for `RemoteZkPlug::prove` / `RemoteZkPlugServer::handle_prove`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProveRequest {
+    /// The input to prove
+    pub input: Vec<u8>,
+    /// Optional public inputs
+    pub public_inputs: Option<Vec<u8>>,
+    /// Optional proving configuration
+    pub config: Option<ZkConfig>,
+}
+
+/// Wire-format request body for `RemoteZkPlug::verify` / `RemoteZkPlugServer::handle_verify`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyRequest {
+    /// The proof being verified, in the remote backend's own proof representation
+    pub proof: ZkProof<Vec<u8>>,
+    /// Optional public inputs
+    pub public_inputs: Option<Vec<u8>>,
+    /// Optional verification configuration
+    pub config: Option<ZkConfig>,
+}
+
+/// Wire-format response body for `RemoteZkPlugServer::handle_verify`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyResponse {
+    /// Whether the proof verified successfully
+    pub valid: bool,
+}
+
+/// Wire-format request body for `RemoteZkPlug::execute` / `RemoteZkPlugServer::handle_execute`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecuteRequest {
+    /// The program to execute
+    pub program: Vec<u8>,
+    /// The input to execute the program with
+    pub input: Vec<u8>,
+    /// Optional public inputs
+    pub public_inputs: Option<Vec<u8>>,
+    /// Optional execution/proving configuration
+    pub config: Option<ZkConfig>,
+}
+
+/// Minimal HTTP client surface [`RemoteZkPlug`] needs.
+///
+/// Kept as a trait (rather than hard-coding an HTTP client crate) so callers can plug in whatever
+/// client their deployment already uses; implementations are responsible for turning transport
+/// failures into `ZkError::Network`.
+#[async_trait]
+pub trait HttpClient: Send + Sync + fmt::Debug {
+    /// POST `body` (JSON) to `{base_url}{path}` and return the response body bytes.
+    async fn post_json(
+        &self,
+        base_url: &str,
+        path: &str,
+        body: Vec<u8>,
+    ) -> Result<Vec<u8>, ZkError>;
+}
+
+/// [`ZkPlug`] implementation that forwards every operation to a remote prover over HTTP(S),
+/// speaking the protocol [`RemoteZkPlugServer`] hosts.
+///
+/// The remote's proof representation is treated as opaque bytes (`Self::Proof = Vec<u8>`), since
+/// the client has no way to know the remote's concrete proof type.
+#[derive(Debug)]
+pub struct RemoteZkPlug<C: HttpClient> {
+    id: &'static str,
+    base_url: String,
+    client: C,
+    request_timeout: Duration,
+}
+
+impl<C: HttpClient> RemoteZkPlug<C> {
+    /// Create a client for a remote prover hosted at `base_url`.
+    pub fn new(id: &'static str, base_url: impl Into<String>, client: C) -> Self {
+        Self {
+            id,
+            base_url: base_url.into(),
+            client,
+            request_timeout: Duration::from_secs(300),
+        }
+    }
+
+    /// Override the default 5-minute per-request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    async fn call<Req, Resp>(&self, path: &str, request: &Req) -> Result<Resp, ZkError>
+    where
+        Req: Serialize + Sync,
+        Resp: for<'de> Deserialize<'de>,
+    {
+        let body =
+            serde_json::to_vec(request).map_err(|e| ZkError::Serialization(e.to_string()))?;
+        let raw = match tokio::time::timeout(
+            self.request_timeout,
+            self.client.post_json(&self.base_url, path, body),
+        )
+        .await
+        {
+            Ok(result) => result?,
+            Err(_) => return Err(ZkError::Timeout(self.request_timeout)),
+        };
+        serde_json::from_slice(&raw).map_err(|e| ZkError::Serialization(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl<C: HttpClient + 'static> ZkPlug for RemoteZkPlug<C> {
+    type Proof = Vec<u8>;
+    type Error = ZkError;
+
+    async fn prove(
+        &self,
+        input: &[u8],
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> Result<ZkProof<Self::Proof>, Self::Error> {
+        self.call(
+            "/prove",
+            &ProveRequest {
+                input: input.to_vec(),
+                public_inputs: public_inputs.map(|b| b.to_vec()),
+                config: config.cloned(),
+            },
+        )
+        .await
+    }
+
+    async fn verify(
+        &self,
+        proof: &ZkProof<Self::Proof>,
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> Result<bool, Self::Error> {
+        let response: VerifyResponse = self
+            .call(
+                "/verify",
+                &VerifyRequest {
+                    proof: proof.clone(),
+                    public_inputs: public_inputs.map(|b| b.to_vec()),
+                    config: config.cloned(),
+                },
+            )
+            .await?;
+        Ok(response.valid)
+    }
+
+    async fn execute(
+        &self,
+        program: &[u8],
+        input: &[u8],
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> Result<ExecutionResult<Self::Proof>, Self::Error> {
+        self.call(
+            "/execute",
+            &ExecuteRequest {
+                program: program.to_vec(),
+                input: input.to_vec(),
+                public_inputs: public_inputs.map(|b| b.to_vec()),
+                config: config.cloned(),
+            },
+        )
+        .await
+    }
+
+    async fn get_backend_info(&self) -> BackendInfo {
+        match self.call::<(), BackendInfo>("/info", &()).await {
+            Ok(info) => info,
+            Err(e) => BackendInfo {
+                id: self.id.to_string(),
+                name: format!("{} (remote, unreachable)", self.id),
+                version: "unknown".to_string(),
+                capabilities: Vec::new(),
+                health: HealthStatus::Unhealthy(e.to_string()),
+                resource_usage: ResourceUsage {
+                    cpu_usage: 0.0,
+                    memory_usage: 0,
+                    available_memory: 0,
+                    active_tasks: 0,
+                    queue_depth: 0,
+                },
+                custom_info: HashMap::new(),
+            },
+        }
+    }
+
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// Always returns an empty list: this method is synchronous, so it cannot make a network
+    /// round-trip, and nothing from `get_backend_info` is cached. Use
+    /// `get_backend_info().await.capabilities` for the remote's actual capabilities.
+    fn capabilities(&self) -> Vec<ZkCapability> {
+        Vec::new()
+    }
+
+    async fn health_check(&self) -> HealthStatus {
+        match self.call::<(), HealthStatus>("/health", &()).await {
+            Ok(status) => status,
+            Err(e) => HealthStatus::Unhealthy(format!("remote health check failed: {e}")),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Configuration for [`RemoteZkPlugServer`]'s CORS policy.
+#[derive(Debug, Clone)]
+pub struct CorsConfig {
+    /// `Access-Control-Allow-Origin` value for the embedding application to send on every
+    /// response; the handlers themselves only produce body bytes. Defaults to `*` (wildcard)
+    /// for local dev; production deployments should restrict this to the dashboard's actual
+    /// origin.
+    pub allow_origin: String,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self { allow_origin: "*".to_string() }
+    }
+}
+
+/// Server-side harness that turns a concrete [`ZkPlug`] into request/response handlers speaking
+/// the same protocol [`RemoteZkPlug`] expects — so browser-based dashboards and `RemoteZkPlug`
+/// clients can reach it directly. Binding these handlers to an actual listener is left to the
+/// embedding application.
+#[derive(Debug)]
+pub struct RemoteZkPlugServer<P: ZkPlug> {
+    plugin: Arc<P>,
+    cors: CorsConfig,
+}
+
+impl<P> RemoteZkPlugServer<P>
+where
+    P: ZkPlug,
+    P::Error: From<ZkError>,
+{
+    /// Host `plugin` behind the remote-prover protocol, with the default (wildcard) CORS policy.
+    pub fn new(plugin: Arc<P>) -> Self {
+        Self { plugin, cors: CorsConfig::default() }
+    }
+
+    /// Override the default CORS policy.
+    pub fn with_cors(mut self, cors: CorsConfig) -> Self {
+        self.cors = cors;
+        self
+    }
+
+    /// The configured `Access-Control-Allow-Origin` value, for the embedding application to
+    /// attach to every response.
+    pub fn allow_origin(&self) -> &str {
+        &self.cors.allow_origin
+    }
+
+    /// Handle a `/prove` request body, returning the JSON response body.
+    pub async fn handle_prove(&self, body: &[u8]) -> Result<Vec<u8>, P::Error> {
+        let request: ProveRequest =
+            serde_json::from_slice(body).map_err(|e| ZkError::Serialization(e.to_string()))?;
+        let proof = self
+            .plugin
+            .prove(
+                &request.input,
+                request.public_inputs.as_deref(),
+                request.config.as_ref(),
+            )
+            .await?;
+        serde_json::to_vec(&proof)
+            .map_err(|e| ZkError::Serialization(e.to_string()).into())
+    }
+
+    /// Handle a `/verify` request body, returning the JSON response body.
+    pub async fn handle_verify(&self, body: &[u8]) -> Result<Vec<u8>, P::Error> {
+        #[derive(Deserialize)]
+        struct Req<Proof> {
+            proof: ZkProof<Proof>,
+            public_inputs: Option<Vec<u8>>,
+            config: Option<ZkConfig>,
+        }
+        let request: Req<P::Proof> =
+            serde_json::from_slice(body).map_err(|e| ZkError::Serialization(e.to_string()))?;
+        let valid = self
+            .plugin
+            .verify(
+                &request.proof,
+                request.public_inputs.as_deref(),
+                request.config.as_ref(),
+            )
+            .await?;
+        serde_json::to_vec(&VerifyResponse { valid })
+            .map_err(|e| ZkError::Serialization(e.to_string()).into())
+    }
+
+    /// Handle an `/execute` request body, returning the JSON response body.
+    pub async fn handle_execute(&self, body: &[u8]) -> Result<Vec<u8>, P::Error> {
+        let request: ExecuteRequest =
+            serde_json::from_slice(body).map_err(|e| ZkError::Serialization(e.to_string()))?;
+        let result = self
+            .plugin
+            .execute(
+                &request.program,
+                &request.input,
+                request.public_inputs.as_deref(),
+                request.config.as_ref(),
+            )
+            .await?;
+        serde_json::to_vec(&result)
+            .map_err(|e| ZkError::Serialization(e.to_string()).into())
+    }
+
+    /// Handle an `/info` request, returning the JSON response body. Proxies the plugin's own
+    /// `get_backend_info`, so `RemoteZkPlug::get_backend_info` reflects this node's real status.
+    pub async fn handle_info(&self) -> Vec<u8> {
+        serde_json::to_vec(&self.plugin.get_backend_info().await).unwrap_or_default()
+    }
+
+    /// Handle a `/health` request, returning the JSON response body. Proxies the plugin's own
+    /// `health_check`, so `RemoteZkPlug::health_check` reflects this node's real status.
+    pub async fn handle_health(&self) -> Vec<u8> {
+        serde_json::to_vec(&self.plugin.health_check().await).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zkplug::{ExecutionStats, ProofMetadata};
+    use std::time::SystemTime;
+
+    /// Trivial `ZkPlug` whose "proof" is just the input bytes, so `RemoteZkPlug`/
+    /// `RemoteZkPlugServer` round trips can be checked without real cryptography.
+    #[derive(Debug)]
+    struct EchoPlug;
+
+    #[async_trait]
+    impl ZkPlug for EchoPlug {
+        type Proof = Vec<u8>;
+        type Error = ZkError;
+
+        async fn prove(
+            &self,
+            input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ZkProof<Self::Proof>, Self::Error> {
+            Ok(ZkProof {
+                proof: input.to_vec(),
+                metadata: ProofMetadata {
+                    timestamp: SystemTime::now(),
+                    generation_time: Duration::default(),
+                    proof_size: input.len(),
+                    backend_id: "echo".to_string(),
+                    circuit_hash: None,
+                    custom_fields: HashMap::new(),
+                },
+            })
+        }
+
+        async fn verify(
+            &self,
+            proof: &ZkProof<Self::Proof>,
+            public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<bool, Self::Error> {
+            Ok(public_inputs.is_none_or(|pi| pi == proof.proof.as_slice()))
+        }
+
+        async fn execute(
+            &self,
+            _program: &[u8],
+            input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ExecutionResult<Self::Proof>, Self::Error> {
+            Ok(ExecutionResult {
+                output: input.to_vec(),
+                proof: ZkProof {
+                    proof: input.to_vec(),
+                    metadata: ProofMetadata {
+                        timestamp: SystemTime::now(),
+                        generation_time: Duration::default(),
+                        proof_size: input.len(),
+                        backend_id: "echo".to_string(),
+                        circuit_hash: None,
+                        custom_fields: HashMap::new(),
+                    },
+                },
+                stats: ExecutionStats {
+                    steps: 1,
+                    memory_usage: 0,
+                    execution_time: Duration::default(),
+                    gas_used: None,
+                },
+            })
+        }
+
+        async fn get_backend_info(&self) -> BackendInfo {
+            BackendInfo {
+                id: "echo".to_string(),
+                name: "echo".to_string(),
+                version: "test".to_string(),
+                capabilities: Vec::new(),
+                health: HealthStatus::Healthy,
+                resource_usage: ResourceUsage {
+                    cpu_usage: 0.0,
+                    memory_usage: 0,
+                    available_memory: usize::MAX,
+                    active_tasks: 0,
+                    queue_depth: 0,
+                },
+                custom_info: HashMap::new(),
+            }
+        }
+
+        fn id(&self) -> &'static str {
+            "echo"
+        }
+
+        fn capabilities(&self) -> Vec<ZkCapability> {
+            Vec::new()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    /// Loopback [`HttpClient`] that dispatches straight into a [`RemoteZkPlugServer`] handler, so
+    /// `RemoteZkPlug` round trips can be tested without a real socket.
+    #[derive(Debug)]
+    struct LoopbackClient {
+        server: Arc<RemoteZkPlugServer<EchoPlug>>,
+    }
+
+    #[async_trait]
+    impl HttpClient for LoopbackClient {
+        async fn post_json(
+            &self,
+            _base_url: &str,
+            path: &str,
+            body: Vec<u8>,
+        ) -> Result<Vec<u8>, ZkError> {
+            match path {
+                "/prove" => self.server.handle_prove(&body).await,
+                "/verify" => self.server.handle_verify(&body).await,
+                "/execute" => self.server.handle_execute(&body).await,
+                "/info" => Ok(self.server.handle_info().await),
+                "/health" => Ok(self.server.handle_health().await),
+                other => Err(ZkError::Network(format!("loopback client: unknown path {other}"))),
+            }
+        }
+    }
+
+    fn loopback_plug() -> RemoteZkPlug<LoopbackClient> {
+        let server = Arc::new(RemoteZkPlugServer::new(Arc::new(EchoPlug)));
+        RemoteZkPlug::new("echo-remote", "http://loopback", LoopbackClient { server })
+    }
+
+    #[tokio::test]
+    async fn remote_prove_and_verify_round_trip() {
+        let plug = loopback_plug();
+        let proof = plug.prove(b"hello", None, None).await.unwrap();
+        assert!(plug.verify(&proof, Some(b"hello"), None).await.unwrap());
+        assert!(!plug.verify(&proof, Some(b"nope"), None).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn remote_execute_round_trip() {
+        let plug = loopback_plug();
+        let result = plug.execute(b"program", b"input", None, None).await.unwrap();
+        assert_eq!(result.output, b"input");
+    }
+
+    #[tokio::test]
+    async fn remote_get_backend_info_reflects_server() {
+        let plug = loopback_plug();
+        let info = plug.get_backend_info().await;
+        assert_eq!(info.id, "echo");
+        assert!(matches!(info.health, HealthStatus::Healthy));
+    }
+
+    #[tokio::test]
+    async fn remote_get_backend_info_reports_unreachable_on_transport_error() {
+        #[derive(Debug)]
+        struct FailingClient;
+
+        #[async_trait]
+        impl HttpClient for FailingClient {
+            async fn post_json(
+                &self,
+                _base_url: &str,
+                _path: &str,
+                _body: Vec<u8>,
+            ) -> Result<Vec<u8>, ZkError> {
+                Err(ZkError::Network("connection refused".to_string()))
+            }
+        }
+
+        let plug = RemoteZkPlug::new("echo-remote", "http://unreachable", FailingClient);
+        let info = plug.get_backend_info().await;
+        assert!(matches!(info.health, HealthStatus::Unhealthy(_)));
+    }
+}
@@ -1,8 +1,14 @@
 //! Core types for the ZK backend interface
 
-use std::time::Duration;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use core::time::Duration;
 use serde::{Serialize, Deserialize};
 
+use crate::clock::Timestamp;
+use crate::codec::CodecKind;
+
 /// Resource usage information for a ZK backend
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResourceUsage {
@@ -42,6 +48,12 @@ pub struct ZkConfig {
     pub verification_timeout: Option<Duration>,
     /// Maximum memory usage allowed in bytes
     pub max_memory: Option<usize>,
+    /// Codec to compress proof bytes with before they leave `prove`, and to decompress before
+    /// `verify`. `None` means proofs are passed through uncompressed.
+    pub proof_codec: Option<CodecKind>,
+    /// Maximum number of items accepted by a single `batch_prove`/`batch_verify` call. Batches
+    /// larger than this are rejected with `ZkError::ResourceLimit` instead of being attempted.
+    pub max_batch_size: Option<usize>,
 }
 
 /// Metadata about a generated proof
@@ -53,8 +65,14 @@ pub struct ProofMetadata {
     pub proof_size: usize,
     /// Hash of the program that generated this proof
     pub program_hash: String,
-    /// Timestamp when the proof was generated
-    pub timestamp: std::time::SystemTime,
+    /// Timestamp when the proof was generated. See [`crate::clock::Clock`] for how this is
+    /// populated under `no_std`.
+    pub timestamp: Timestamp,
+    /// Codec used to compress the proof bytes, as a [`CodecKind`] id. `0` (`CodecKind::None`)
+    /// means the proof is stored/transported uncompressed.
+    pub codec_id: u8,
+    /// Size of the proof after compression, in bytes. `None` when `codec_id` is `CodecKind::None`.
+    pub compressed_size: Option<usize>,
 }
 
 /// Statistics about proof generation/verification
@@ -70,4 +88,38 @@ pub struct ZkStats {
     pub total_verifications: usize,
     /// Total failures
     pub total_failures: usize,
-} 
\ No newline at end of file
+}
+
+/// Statistics about a single `batch_prove`/`batch_verify` call
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BatchStats {
+    /// Number of items folded together in this batch
+    pub batch_size: usize,
+    /// Number of items that completed successfully
+    pub succeeded: usize,
+    /// Number of items that failed
+    pub failed: usize,
+    /// Total wall-clock time spent on the batch
+    pub total_time: Duration,
+    /// Items processed per second, averaged over `total_time`
+    pub throughput: f64,
+}
+
+impl BatchStats {
+    /// Derive batch statistics from per-item outcomes and the total time taken.
+    pub fn from_outcomes(succeeded: usize, failed: usize, total_time: Duration) -> Self {
+        let batch_size = succeeded + failed;
+        let throughput = if total_time.as_secs_f64() > 0.0 {
+            batch_size as f64 / total_time.as_secs_f64()
+        } else {
+            0.0
+        };
+        Self {
+            batch_size,
+            succeeded,
+            failed,
+            total_time,
+            throughput,
+        }
+    }
+}
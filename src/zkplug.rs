@@ -2,17 +2,24 @@
 #![allow(unused_variables)]
 #![allow(dead_code)]
 
-
-///ZkPlug: Zero-Knowledge Backend Abstraction for Frostgate
-/// 
+//! ZkPlug: advanced, aggregation-capable plugin surface for Frostgate
+//!
+//! Where [`crate::backend::ZkBackend`] models the common case ("program + input → proof bytes",
+//! one backend at a time), `ZkPlug` is for the harder cases: backends with their own associated
+//! proof type, VM execution, circuit setup, and — the focus of this module — combining proofs
+//! produced by one or more backends into a single rolled-up proof. The two traits are
+//! intentionally separate rather than one growing to cover both; most backends only need
+//! `ZkBackend`.
 
 use async_trait::async_trait;
+use futures::future::join_all;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 use std::any::Any;
+use tokio::sync::Semaphore;
 
 /// Result type alias for ZkPlug operations
 pub type ZkResult<T, E> = Result<T, E>;
@@ -300,54 +307,139 @@ pub trait ZkPlug: Send + Sync + Debug {
     
     // === Batch Operations ===
 
-    /// Generate multiple proofs in batch (potentially optimized).
-    /// Default implementation falls back to individual proving.
+    /// Generate multiple proofs in batch, scheduled concurrently.
+    ///
+    /// Dispatches up to `config.parallel_workers` proving tasks at once (via a `Semaphore`),
+    /// preserving input order in the returned `Vec`. Per-task memory is estimated from
+    /// `estimated_task_memory` and admission is throttled so the sum of in-flight tasks stays
+    /// under `config.memory_limit`; if a single task alone would exceed that limit the whole batch
+    /// fails fast with `ResourceExhaustion` instead of being attempted. Failures of individual
+    /// items are captured per-slot in the returned `proofs` without aborting the rest of the
+    /// batch.
     async fn prove_batch(
         &self,
         request: &BatchProvingRequest,
     ) -> ZkResult<BatchProvingResult<Self::Proof, Self::Error>, Self::Error> {
-        let mut proofs = Vec::new();
         let config = request.config.as_ref();
-        
-        for (input, pub_input) in request.inputs.iter().zip(request.public_inputs.iter()) {
-            let proof_result = self.prove(input, pub_input.as_deref(), config).await;
-            proofs.push(proof_result);
-        }
-        
+        let admitted = self.batch_admission(config).await?;
+        let semaphore = Semaphore::new(admitted);
+
+        let tasks = request
+            .inputs
+            .iter()
+            .zip(request.public_inputs.iter())
+            .map(|(input, pub_input)| async {
+                let _permit = semaphore
+                    .acquire()
+                    .await
+                    .expect("batch semaphore is never closed while tasks are in flight");
+                self.prove(input, pub_input.as_deref(), config).await
+            });
+
+        let proofs = join_all(tasks).await;
         Ok(BatchProvingResult::<Self::Proof, Self::Error> {
             proofs,
             aggregated_proof: None,
         })
     }
 
-    /// Verify multiple proofs in batch.
-    /// Default implementation falls back to individual verification.
+    /// Verify multiple proofs in batch, scheduled concurrently.
+    ///
+    /// Same admission policy as [`ZkPlug::prove_batch`]: up to `config.parallel_workers`
+    /// verification tasks in flight at once, throttled so estimated in-flight memory stays under
+    /// `config.memory_limit`.
     async fn verify_batch(
         &self,
         proofs: &[ZkProof<Self::Proof>],
         public_inputs: &[Option<Vec<u8>>],
         config: Option<&ZkConfig>,
     ) -> ZkResult<Vec<bool>, Self::Error> {
-        let mut results = Vec::new();
-        
-        for (proof, pub_input) in proofs.iter().zip(public_inputs.iter()) {
-            let result = self.verify(proof, pub_input.as_deref(), config).await?;
-            results.push(result);
+        let admitted = self.batch_admission(config).await?;
+        let semaphore = Semaphore::new(admitted);
+
+        let tasks = proofs
+            .iter()
+            .zip(public_inputs.iter())
+            .map(|(proof, pub_input)| async {
+                let _permit = semaphore
+                    .acquire()
+                    .await
+                    .expect("batch semaphore is never closed while tasks are in flight");
+                self.verify(proof, pub_input.as_deref(), config).await
+            });
+
+        join_all(tasks).await.into_iter().collect()
+    }
+
+    /// Estimated peak memory (in bytes) a single `prove`/`verify` task needs, used by
+    /// [`ZkPlug::batch_admission`] to throttle concurrency under `config.memory_limit`.
+    ///
+    /// `None` (the default) means the backend hasn't declared one; `batch_admission` then falls
+    /// back to admitting one task at a time rather than guessing, since [`ZkPlug::get_resource_usage`]'s
+    /// default `memory_usage: 0` describes current idle usage, not what a task costs, and treating
+    /// it as a per-task estimate let any `memory_limit` through unthrottled. Backends that know
+    /// their own footprint (proving key size, witness size, etc.) should override this.
+    fn estimated_task_memory(&self) -> Option<usize> {
+        None
+    }
+
+    /// Work out how many batch tasks to admit concurrently: `config.parallel_workers`, further
+    /// capped so that `admitted * estimated_task_memory() <= config.memory_limit` when a memory
+    /// limit is set. Errors with `ResourceExhaustion` if a single task alone would exceed the
+    /// limit. When the backend hasn't overridden [`ZkPlug::estimated_task_memory`], falls back to
+    /// admitting a single task at a time rather than pretending the limit doesn't apply.
+    async fn batch_admission(&self, config: Option<&ZkConfig>) -> Result<usize, Self::Error> {
+        let workers = config.and_then(|c| c.parallel_workers).unwrap_or(1).max(1);
+        let Some(limit) = config.and_then(|c| c.memory_limit) else {
+            return Ok(workers);
+        };
+
+        let Some(per_task_estimate) = self.estimated_task_memory() else {
+            return Ok(1);
+        };
+        let per_task_estimate = per_task_estimate.max(1);
+        if per_task_estimate > limit {
+            return Err(ZkError::ResourceExhaustion(format!(
+                "a single task alone needs ~{per_task_estimate} bytes, over the {limit} byte memory_limit"
+            ))
+            .into());
         }
-        
-        Ok(results)
+
+        Ok(workers.min((limit / per_task_estimate).max(1)))
     }
 
     // === Aggregation & Recursion ===
 
-    /// Aggregate multiple proofs into a single proof.
-    /// Returns an error with `Unsupported` if the backend doesn't support aggregation.
+    /// Accumulator-extraction hook for backends that support cross-backend aggregation (flagged
+    /// via `ZkCapability::UniversalSetup` + `ZkCapability::Aggregation`). `None` by default,
+    /// meaning `aggregate_proofs` falls back to returning `Unsupported`.
+    fn as_aggregator(&self) -> Option<&dyn ZkAggregator> {
+        None
+    }
+
+    /// Aggregate multiple proofs, each paired with the public inputs it was proven against, into
+    /// a single proof. The public inputs are required (not just the proof bytes) because
+    /// accumulator extraction runs each proof's verifier transcript symbolically — that transcript
+    /// depends on the public inputs the proof was generated against, so omitting them would yield
+    /// an unsound accumulator.
+    ///
+    /// Dispatches through [`AggregationEngine`] when this backend both advertises
+    /// `ZkCapability::UniversalSetup` + `ZkCapability::Aggregation` and implements
+    /// [`ZkPlug::as_aggregator`]. Returns an error with `Unsupported` otherwise.
     async fn aggregate_proofs(
         &self,
-        proofs: &[ZkProof<Self::Proof>],
+        proofs: &[(ZkProof<Self::Proof>, Vec<u8>)],
         config: Option<&ZkConfig>,
     ) -> ZkResult<ZkProof<Self::Proof>, Self::Error> {
-        Err(ZkError::Unsupported("Proof aggregation not supported".to_string()).into())
+        let capable = self.supports_capability(&ZkCapability::UniversalSetup)
+            && self.supports_capability(&ZkCapability::Aggregation);
+        match (capable, self.as_aggregator()) {
+            (true, Some(aggregator)) => {
+                let engine = AggregationEngine::new(aggregator);
+                engine.aggregate(proofs).await.map_err(Into::into)
+            }
+            _ => Err(ZkError::Unsupported("Proof aggregation not supported".to_string()).into()),
+        }
     }
 
     /// Create a recursive proof (proof of proof verification).
@@ -360,6 +452,145 @@ pub trait ZkPlug: Send + Sync + Debug {
         Err(ZkError::Unsupported("Recursive proofs not supported".to_string()).into())
     }
 
+    /// Continuation-extraction hook for ZK-VM backends that can split a long execution trace
+    /// into independently-provable segments (flagged via `ZkCapability::Incremental` +
+    /// `ZkCapability::Recursion`). `None` by default, meaning `execute_continuation` falls back
+    /// to returning `Unsupported`.
+    fn as_segmented_executor(&self) -> Option<&dyn SegmentedExecutor> {
+        None
+    }
+
+    /// Execute a program whose trace is too long for one circuit by splitting it into
+    /// continuation segments, proving each independently (concurrently, under the same
+    /// admission policy as [`ZkPlug::prove_batch`]), then folding adjacent segment proofs into a
+    /// single root proof in a binary recursion tree — each fold checking that the left child's
+    /// `state_out` matches the right child's `state_in` before combining them. The root proof's
+    /// metadata records `initial_state`/`final_state` in `custom_fields` and the program's hash as
+    /// `circuit_hash`; the root proof's own public input is whatever
+    /// [`SegmentedExecutor::fold_segments`] produces, and no program hash is passed to it.
+    /// `ExecutionResult.stats.steps` reports the total cycle count across all segments.
+    ///
+    /// Requires `ZkCapability::Incremental` + `ZkCapability::Recursion` and an
+    /// [`ZkPlug::as_segmented_executor`] implementation; returns `Unsupported` otherwise.
+    async fn execute_continuation(
+        &self,
+        program: &[u8],
+        input: &[u8],
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<ExecutionResult<Self::Proof>, Self::Error> {
+        let capable = self.supports_capability(&ZkCapability::Incremental)
+            && self.supports_capability(&ZkCapability::Recursion);
+        let Some(executor) = capable.then(|| self.as_segmented_executor()).flatten() else {
+            return Err(ZkError::Unsupported(
+                "Continuation-based segment proving not supported".to_string(),
+            )
+            .into());
+        };
+
+        let start = std::time::Instant::now();
+        let program_hash = hash_circuit(program);
+        let (segments, output) = executor.split_into_segments(program, input).await?;
+        let Some(last_segment) = segments.last() else {
+            return Err(ZkError::InvalidInput(
+                "execute_continuation: program produced no segments".to_string(),
+            )
+            .into());
+        };
+        let initial_state = segments[0].state_in.clone();
+        let final_state = last_segment.state_out.clone();
+        let total_steps: u64 = segments
+            .iter()
+            .map(|s| s.state_out.cycle_count - s.state_in.cycle_count)
+            .sum();
+
+        // Prove every segment concurrently, under the same admission policy as `prove_batch`.
+        let admitted = self.batch_admission(config).await?;
+        let semaphore = Semaphore::new(admitted);
+        let mut level: Vec<(VmCheckpoint, VmCheckpoint, Vec<u8>)> = join_all(segments.iter().map(
+            |segment| async {
+                let _permit = semaphore
+                    .acquire()
+                    .await
+                    .expect("segment semaphore is never closed while tasks are in flight");
+                executor
+                    .prove_segment(segment)
+                    .await
+                    .map(|bytes| (segment.state_in.clone(), segment.state_out.clone(), bytes))
+            },
+        ))
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, ZkError>>()?;
+
+        // Fold adjacent segment proofs in a binary tree until one root proof remains.
+        while level.len() > 1 {
+            let mut next = Vec::with_capacity(level.len().div_ceil(2));
+            let mut pairs = level.into_iter();
+            while let Some(left) = pairs.next() {
+                match pairs.next() {
+                    Some(right) => {
+                        if left.1 != right.0 {
+                            return Err(ZkError::InvalidInput(format!(
+                                "execute_continuation: segment boundary mismatch, left ends at \
+                                 {:?} but right starts at {:?}",
+                                left.1, right.0
+                            ))
+                            .into());
+                        }
+                        let folded = executor
+                            .fold_segments(
+                                (&left.0, &left.1, &left.2),
+                                (&right.0, &right.1, &right.2),
+                            )
+                            .await?;
+                        next.push((left.0, right.1, folded));
+                    }
+                    None => next.push(left),
+                }
+            }
+            level = next;
+        }
+        let (_, _, root_proof_bytes) = level
+            .into_iter()
+            .next()
+            .expect("a non-empty segment list always folds to exactly one root");
+
+        let proof: Self::Proof = bincode::deserialize(&root_proof_bytes)
+            .map_err(|e| ZkError::Serialization(e.to_string()))?;
+
+        let mut custom_fields = HashMap::new();
+        custom_fields.insert("segment_count".to_string(), serde_json::Value::from(segments.len()));
+        custom_fields.insert(
+            "initial_state".to_string(),
+            serde_json::to_value(&initial_state).unwrap_or(serde_json::Value::Null),
+        );
+        custom_fields.insert(
+            "final_state".to_string(),
+            serde_json::to_value(&final_state).unwrap_or(serde_json::Value::Null),
+        );
+
+        Ok(ExecutionResult {
+            output,
+            proof: ZkProof {
+                proof,
+                metadata: ProofMetadata {
+                    timestamp: SystemTime::now(),
+                    generation_time: start.elapsed(),
+                    proof_size: root_proof_bytes.len(),
+                    backend_id: self.id().to_string(),
+                    circuit_hash: Some(program_hash),
+                    custom_fields,
+                },
+            },
+            stats: ExecutionStats {
+                steps: total_steps,
+                memory_usage: 0,
+                execution_time: start.elapsed(),
+                gas_used: None,
+            },
+        })
+    }
+
     // === Circuit Management ===
 
     /// Compile and setup a circuit (for circuit-based backends).
@@ -377,6 +608,108 @@ pub trait ZkPlug: Send + Sync + Debug {
         Err(ZkError::Unsupported("Circuit info not available".to_string()).into())
     }
 
+    /// `setup_circuit`, but also invalidating any proofs cached (via [`ZkPlug::proof_cache`])
+    /// against the resulting circuit id — a recompiled circuit's old cached proofs are no longer
+    /// valid against it.
+    async fn setup_circuit_cached(
+        &self,
+        circuit_code: &[u8],
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<CircuitInfo, Self::Error> {
+        let info = self.setup_circuit(circuit_code, config).await?;
+        if let Some(cache) = self.proof_cache() {
+            cache.invalidate_circuit(&info.circuit_id).await;
+        }
+        Ok(info)
+    }
+
+    // === Proof Caching ===
+
+    /// Pluggable [`ProofCache`] backing [`ZkPlug::prove_cached`]/[`ZkPlug::execute_cached`].
+    /// `None` by default, meaning those wrappers just call through to `prove`/`execute`
+    /// unconditionally.
+    fn proof_cache(&self) -> Option<&dyn ProofCache> {
+        None
+    }
+
+    /// `prove`, but honoring `config.enable_caching` (defaulting to enabled when `config` is
+    /// `None`, matching [`ZkConfig::default`]): if a cache is configured via
+    /// [`ZkPlug::proof_cache`], a content-hash hit (see [`cache_key`]) returns the stored proof
+    /// immediately with `ProofMetadata.custom_fields["cache_hit"] = true`; a miss proves normally
+    /// and populates the cache. `circuit_hash` should identify the compiled circuit `input` is
+    /// proven against (e.g. from `setup_circuit`'s `CircuitInfo::circuit_id`).
+    async fn prove_cached(
+        &self,
+        circuit_hash: &str,
+        input: &[u8],
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<ZkProof<Self::Proof>, Self::Error> {
+        let caching_enabled = config.map(|c| c.enable_caching).unwrap_or(true);
+        let Some(cache) = caching_enabled.then(|| self.proof_cache()).flatten() else {
+            return self.prove(input, public_inputs, config).await;
+        };
+
+        let key = cache_key(self.id(), circuit_hash.as_bytes(), input, public_inputs, config);
+        if let Some(bytes) = cache.get(&key).await {
+            if let Ok(mut proof) = self.deserialize_proof(&bytes) {
+                proof
+                    .metadata
+                    .custom_fields
+                    .insert("cache_hit".to_string(), serde_json::Value::from(true));
+                return Ok(proof);
+            }
+        }
+
+        let proof = self.prove(input, public_inputs, config).await?;
+        if let Ok(bytes) = self.serialize_proof(&proof) {
+            cache.put(key, circuit_hash.to_string(), bytes).await;
+        }
+        Ok(proof)
+    }
+
+    /// `execute`, but honoring `config.enable_caching` the same way [`ZkPlug::prove_cached`]
+    /// does. The whole `ExecutionResult` (output, proof, stats) is cached, keyed on `program`'s
+    /// hash rather than a pre-compiled circuit id since ZK-VM programs aren't separately set up.
+    async fn execute_cached(
+        &self,
+        program: &[u8],
+        input: &[u8],
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<ExecutionResult<Self::Proof>, Self::Error> {
+        let caching_enabled = config.map(|c| c.enable_caching).unwrap_or(true);
+        let Some(cache) = caching_enabled.then(|| self.proof_cache()).flatten() else {
+            return self.execute(program, input, public_inputs, config).await;
+        };
+
+        let circuit_hash = hash_circuit(program);
+        let key = cache_key(self.id(), program, input, public_inputs, config);
+        if let Some(bytes) = cache.get(&key).await {
+            if let Ok(mut result) = bincode::deserialize::<ExecutionResult<Self::Proof>>(&bytes) {
+                result
+                    .proof
+                    .metadata
+                    .custom_fields
+                    .insert("cache_hit".to_string(), serde_json::Value::from(true));
+                return Ok(result);
+            }
+        }
+
+        let result = self.execute(program, input, public_inputs, config).await?;
+        if let Ok(bytes) = bincode::serialize(&result) {
+            cache.put(key, circuit_hash, bytes).await;
+        }
+        Ok(result)
+    }
+
+    /// Flush every entry from the configured [`ZkPlug::proof_cache`], if any.
+    async fn clear_proof_cache(&self) {
+        if let Some(cache) = self.proof_cache() {
+            cache.clear().await;
+        }
+    }
+
     // === Metadata & Information ===
 
     /// Return detailed information about this backend.
@@ -440,9 +773,468 @@ pub trait ZkPlug: Send + Sync + Debug {
     }
 }
 
-/// A registry for managing multiple ZK backends
+/// A backend's deferred pairing/instance accumulator for one proof, extracted rather than fully
+/// verified, so [`AggregationEngine`] can fold many of them into a single root-circuit check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccumulatorInstance {
+    /// Left (L) accumulator point (e.g. a KZG/pairing accumulator commitment)
+    pub left: Vec<u8>,
+    /// Right (R) accumulator point
+    pub right: Vec<u8>,
+    /// Public inputs this accumulator instance attests to
+    pub public_inputs: Vec<u8>,
+    /// Hash of the circuit/program the source proof was generated against
+    pub circuit_hash: String,
+}
+
+/// Hook implemented by backends that can participate in cross-backend proof aggregation.
+///
+/// Rather than re-running each inner proof's verifier in full, an aggregator runs the verifier
+/// transcript symbolically to extract the proof's deferred pairing/instance accumulator, and
+/// folds a batch of these into one. This mirrors the SuperCircuit "root circuit" pattern, where a
+/// verifier-in-circuit accumulates the checks of each inner proof instead of re-running them.
+#[async_trait]
+pub trait ZkAggregator: Send + Sync {
+    /// Run `proof`'s verifier transcript symbolically and extract its deferred accumulator,
+    /// instead of fully verifying it.
+    async fn extract_accumulator(
+        &self,
+        proof: &[u8],
+        public_inputs: &[u8],
+    ) -> Result<AccumulatorInstance, ZkError>;
+
+    /// Fold a batch of accumulator instances into a single succeeding accumulator, returning the
+    /// bytes of the resulting aggregated proof. Validity of the output implies every input
+    /// accumulator was valid.
+    async fn fold(&self, accumulators: &[AccumulatorInstance]) -> Result<Vec<u8>, ZkError>;
+}
+
+/// Drives real cross-backend proof aggregation via an accumulation/root-circuit layer, folding N
+/// proofs into one succinct proof instead of [`ZkPlug::aggregate_proofs`]'s old
+/// always-`Unsupported` stub.
+pub struct AggregationEngine<'a> {
+    aggregator: &'a dyn ZkAggregator,
+}
+
+impl<'a> AggregationEngine<'a> {
+    /// Build an engine around a backend's accumulator-extraction hook.
+    pub fn new(aggregator: &'a dyn ZkAggregator) -> Self {
+        Self { aggregator }
+    }
+
+    /// Aggregate `proofs` (each paired with its public inputs) into one `ZkProof<P>`. The
+    /// resulting proof's `ProofMetadata.custom_fields` records `aggregated_count` and
+    /// `aggregated_circuit_hashes` for the children that were folded in.
+    pub async fn aggregate<P>(
+        &self,
+        proofs: &[(ZkProof<P>, Vec<u8>)],
+    ) -> Result<ZkProof<P>, ZkError>
+    where
+        P: Serialize + for<'de> Deserialize<'de> + Send + Sync + Clone,
+    {
+        if proofs.is_empty() {
+            return Err(ZkError::InvalidInput("aggregate: no proofs supplied".to_string()));
+        }
+
+        let mut accumulators = Vec::with_capacity(proofs.len());
+        let mut circuit_hashes = Vec::with_capacity(proofs.len());
+        for (proof, public_inputs) in proofs {
+            let proof_bytes =
+                bincode::serialize(proof).map_err(|e| ZkError::Serialization(e.to_string()))?;
+            let accumulator = self
+                .aggregator
+                .extract_accumulator(&proof_bytes, public_inputs)
+                .await?;
+            circuit_hashes.push(accumulator.circuit_hash.clone());
+            accumulators.push(accumulator);
+        }
+
+        let folded_bytes = self.aggregator.fold(&accumulators).await?;
+        let aggregated_proof: P = bincode::deserialize(&folded_bytes)
+            .map_err(|e| ZkError::Serialization(e.to_string()))?;
+
+        let mut custom_fields = HashMap::new();
+        custom_fields.insert(
+            "aggregated_count".to_string(),
+            serde_json::Value::from(proofs.len()),
+        );
+        custom_fields.insert(
+            "aggregated_circuit_hashes".to_string(),
+            serde_json::Value::from(circuit_hashes),
+        );
+
+        Ok(ZkProof {
+            proof: aggregated_proof,
+            metadata: ProofMetadata {
+                timestamp: SystemTime::now(),
+                generation_time: Duration::default(),
+                proof_size: folded_bytes.len(),
+                backend_id: "aggregation-engine".to_string(),
+                circuit_hash: None,
+                custom_fields,
+            },
+        })
+    }
+}
+
+/// A checkpoint of ZK-VM state captured at a continuation segment boundary: the pieces needed to
+/// assert `(state_in, state_out)` as a segment's public input and to check that adjacent segments
+/// line up during [`ZkPlug::execute_continuation`]'s recursive fold.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VmCheckpoint {
+    /// Register file contents, backend-specific encoding
+    pub registers: Vec<u8>,
+    /// Root hash of the VM's memory at this checkpoint
+    pub memory_root: Vec<u8>,
+    /// Program counter
+    pub pc: u64,
+    /// Total cycles executed up to this checkpoint
+    pub cycle_count: u64,
+}
+
+/// One segment of a continuation-split execution: the state transition `state_in -> state_out`
+/// plus the segment-local input needed to prove it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VmSegment {
+    /// VM state at the start of this segment
+    pub state_in: VmCheckpoint,
+    /// VM state at the end of this segment
+    pub state_out: VmCheckpoint,
+    /// Segment-local input data; exact shape is backend-specific and opaque at this layer
+    pub segment_input: Vec<u8>,
+}
+
+/// Hook implemented by ZK-VM backends that support continuation-based segment proving: running a
+/// program to completion while checkpointing state at fixed cycle boundaries, proving each
+/// resulting segment independently, and folding adjacent segment proofs together.
+#[async_trait]
+pub trait SegmentedExecutor: Send + Sync {
+    /// Run `program` on `input` to completion, checkpointing the full VM state (registers, memory
+    /// root, program counter, cycle count) at fixed cycle boundaries. Returns the ordered list of
+    /// segments plus the program's final output bytes.
+    async fn split_into_segments(
+        &self,
+        program: &[u8],
+        input: &[u8],
+    ) -> Result<(Vec<VmSegment>, Vec<u8>), ZkError>;
+
+    /// Prove a single segment's state transition, with public inputs asserting
+    /// `(state_in, state_out)`. Returns the segment proof's serialized bytes.
+    async fn prove_segment(&self, segment: &VmSegment) -> Result<Vec<u8>, ZkError>;
+
+    /// Verify that two adjacent segment (or already-folded) proofs share a consistent boundary —
+    /// `left`'s `state_out` equals `right`'s `state_in` — and fold them into a single recursive
+    /// proof covering `left`'s `state_in` through `right`'s `state_out`. Each argument is
+    /// `(state_in, state_out, proof_bytes)`.
+    async fn fold_segments(
+        &self,
+        left: (&VmCheckpoint, &VmCheckpoint, &[u8]),
+        right: (&VmCheckpoint, &VmCheckpoint, &[u8]),
+    ) -> Result<Vec<u8>, ZkError>;
+}
+
+/// Compute the content-hash cache key for a [`ZkPlug::prove_cached`]/[`ZkPlug::execute_cached`]
+/// request: the backend `id`, the circuit hash (or raw program bytes, if no circuit has been
+/// separately set up), the `input`, the `public_inputs`, and the cache-relevant parts of
+/// `config` — `hardware_acceleration` and `memory_limit`, since those can change what a backend
+/// actually produces for the same logical request.
+pub fn cache_key(
+    id: &str,
+    circuit_hash_or_program: &[u8],
+    input: &[u8],
+    public_inputs: Option<&[u8]>,
+    config: Option<&ZkConfig>,
+) -> String {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(id.as_bytes());
+    bytes.push(0);
+    bytes.extend_from_slice(circuit_hash_or_program);
+    bytes.push(0);
+    bytes.extend_from_slice(input);
+    bytes.push(0);
+    if let Some(pub_inputs) = public_inputs {
+        bytes.extend_from_slice(pub_inputs);
+    }
+    if let Some(config) = config {
+        bytes.push(0);
+        bytes.push(config.hardware_acceleration as u8);
+        if let Some(limit) = config.memory_limit {
+            bytes.extend_from_slice(&limit.to_le_bytes());
+        }
+    }
+    hash_circuit(&bytes)
+}
+
+/// Pluggable cache for previously-computed proofs, keyed by [`cache_key`]. Backed into
+/// [`ZkPlug::prove_cached`]/[`ZkPlug::execute_cached`] so that re-requesting an identical proof
+/// (common across retries of the same state transition) skips re-proving entirely.
+#[async_trait]
+pub trait ProofCache: Send + Sync + Debug {
+    /// Look up a cached entry's serialized bytes by its content-hash key.
+    async fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// Insert an entry's serialized bytes under `key`, tagged with the circuit/program hash it
+    /// was generated against (for [`ProofCache::invalidate_circuit`]).
+    async fn put(&self, key: String, circuit_hash: String, bytes: Vec<u8>);
+
+    /// Remove every cached entry that was generated against `circuit_hash`. Called when a circuit
+    /// recompiles, via [`ZkPlug::setup_circuit_cached`].
+    async fn invalidate_circuit(&self, circuit_hash: &str);
+
+    /// Remove every cached entry.
+    async fn clear(&self);
+}
+
+/// In-memory [`ProofCache`] bounded to `capacity` entries, evicting the least-recently-used entry
+/// once that's exceeded.
+#[derive(Debug)]
+pub struct LruProofCache {
+    capacity: usize,
+    state: tokio::sync::Mutex<LruState>,
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    entries: HashMap<String, (String, Vec<u8>)>,
+    /// Key order, least-recently-used first.
+    order: std::collections::VecDeque<String>,
+}
+
+impl LruProofCache {
+    /// Create an in-memory cache holding at most `capacity` entries (minimum 1).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: tokio::sync::Mutex::new(LruState::default()),
+        }
+    }
+
+    fn touch(state: &mut LruState, key: &str) {
+        if let Some(pos) = state.order.iter().position(|k| k == key) {
+            state.order.remove(pos);
+        }
+        state.order.push_back(key.to_string());
+    }
+}
+
+#[async_trait]
+impl ProofCache for LruProofCache {
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut state = self.state.lock().await;
+        if state.entries.contains_key(key) {
+            Self::touch(&mut state, key);
+        }
+        state.entries.get(key).map(|(_, bytes)| bytes.clone())
+    }
+
+    async fn put(&self, key: String, circuit_hash: String, bytes: Vec<u8>) {
+        let mut state = self.state.lock().await;
+        Self::touch(&mut state, &key);
+        state.entries.insert(key, (circuit_hash, bytes));
+        while state.order.len() > self.capacity {
+            if let Some(oldest) = state.order.pop_front() {
+                state.entries.remove(&oldest);
+            }
+        }
+    }
+
+    async fn invalidate_circuit(&self, circuit_hash: &str) {
+        let mut state = self.state.lock().await;
+        let stale: Vec<String> = state
+            .entries
+            .iter()
+            .filter(|(_, (hash, _))| hash == circuit_hash)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in stale {
+            state.entries.remove(&key);
+            if let Some(pos) = state.order.iter().position(|k| k == &key) {
+                state.order.remove(pos);
+            }
+        }
+    }
+
+    async fn clear(&self) {
+        let mut state = self.state.lock().await;
+        state.entries.clear();
+        state.order.clear();
+    }
+}
+
+/// Disk-backed [`ProofCache`], storing each entry as a file under `base_dir` named by its cache
+/// key, alongside a sibling `.circuit` file recording the circuit hash it was tagged with. Keeps
+/// no in-memory index — every lookup hits the filesystem directly — trading lookup speed for
+/// surviving process restarts, which matters for long-running provers that get redeployed.
+#[derive(Debug, Clone)]
+pub struct DiskProofCache {
+    base_dir: std::path::PathBuf,
+}
+
+impl DiskProofCache {
+    /// Create a cache rooted at `base_dir`, which is created on first write if missing.
+    pub fn new(base_dir: impl Into<std::path::PathBuf>) -> Self {
+        Self { base_dir: base_dir.into() }
+    }
+
+    fn entry_path(&self, key: &str) -> std::path::PathBuf {
+        self.base_dir.join(format!("{key}.proof"))
+    }
+
+    fn circuit_marker_path(&self, key: &str) -> std::path::PathBuf {
+        self.base_dir.join(format!("{key}.circuit"))
+    }
+}
+
+#[async_trait]
+impl ProofCache for DiskProofCache {
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        tokio::fs::read(self.entry_path(key)).await.ok()
+    }
+
+    async fn put(&self, key: String, circuit_hash: String, bytes: Vec<u8>) {
+        if tokio::fs::create_dir_all(&self.base_dir).await.is_err() {
+            return;
+        }
+        let _ = tokio::fs::write(self.entry_path(&key), &bytes).await;
+        let _ = tokio::fs::write(self.circuit_marker_path(&key), circuit_hash.as_bytes()).await;
+    }
+
+    async fn invalidate_circuit(&self, circuit_hash: &str) {
+        let Ok(mut dir) = tokio::fs::read_dir(&self.base_dir).await else {
+            return;
+        };
+        while let Ok(Some(entry)) = dir.next_entry().await {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("circuit") {
+                continue;
+            }
+            if tokio::fs::read_to_string(&path).await.ok().as_deref() == Some(circuit_hash) {
+                let _ = tokio::fs::remove_file(&path).await;
+                let _ = tokio::fs::remove_file(path.with_extension("proof")).await;
+            }
+        }
+    }
+
+    async fn clear(&self) {
+        let _ = tokio::fs::remove_dir_all(&self.base_dir).await;
+    }
+}
+
+/// Object-safe adapter over a concrete [`ZkPlug`], normalizing its associated `Proof`/`Error`
+/// types down to opaque bytes and [`ZkError`] so heterogeneous backends can be stored uniformly
+/// in [`ZkPluginRegistry`]. Proofs cross this boundary via the plugin's own
+/// `serialize_proof`/`deserialize_proof`. Implemented for every `ZkPlug` by the blanket impl
+/// below — plugins never need to implement this themselves.
+#[async_trait]
+pub trait ErasedZkPlug: Send + Sync + Debug {
+    /// Generate a proof, returning its serialized bytes plus metadata.
+    async fn prove_erased(
+        &self,
+        input: &[u8],
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> Result<(Vec<u8>, ProofMetadata), ZkError>;
+
+    /// Verify a proof given as serialized bytes.
+    async fn verify_erased(
+        &self,
+        proof_bytes: &[u8],
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> Result<bool, ZkError>;
+
+    /// See [`ZkPlug::id`].
+    fn id(&self) -> &'static str;
+
+    /// See [`ZkPlug::capabilities`].
+    fn capabilities(&self) -> Vec<ZkCapability>;
+
+    /// Extract this plugin's deferred accumulator for `proof_bytes`, via
+    /// [`ZkPlug::as_aggregator`]. `Ok(None)` if this plugin doesn't implement accumulator
+    /// extraction.
+    async fn extract_accumulator_erased(
+        &self,
+        proof_bytes: &[u8],
+        public_inputs: &[u8],
+    ) -> Result<Option<AccumulatorInstance>, ZkError>;
+
+    /// Fold a batch of accumulator instances into one aggregated proof's bytes, via
+    /// [`ZkPlug::as_aggregator`]. `Ok(None)` if this plugin doesn't implement accumulator
+    /// folding.
+    async fn fold_accumulators_erased(
+        &self,
+        accumulators: &[AccumulatorInstance],
+    ) -> Result<Option<Vec<u8>>, ZkError>;
+}
+
+#[async_trait]
+impl<P> ErasedZkPlug for P
+where
+    P: ZkPlug + 'static,
+    P::Error: Into<ZkError>,
+{
+    async fn prove_erased(
+        &self,
+        input: &[u8],
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> Result<(Vec<u8>, ProofMetadata), ZkError> {
+        let proof = ZkPlug::prove(self, input, public_inputs, config)
+            .await
+            .map_err(Into::into)?;
+        let metadata = proof.metadata.clone();
+        let bytes = self.serialize_proof(&proof).map_err(Into::into)?;
+        Ok((bytes, metadata))
+    }
+
+    async fn verify_erased(
+        &self,
+        proof_bytes: &[u8],
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> Result<bool, ZkError> {
+        let proof = self.deserialize_proof(proof_bytes).map_err(Into::into)?;
+        ZkPlug::verify(self, &proof, public_inputs, config)
+            .await
+            .map_err(Into::into)
+    }
+
+    fn id(&self) -> &'static str {
+        ZkPlug::id(self)
+    }
+
+    fn capabilities(&self) -> Vec<ZkCapability> {
+        ZkPlug::capabilities(self)
+    }
+
+    async fn extract_accumulator_erased(
+        &self,
+        proof_bytes: &[u8],
+        public_inputs: &[u8],
+    ) -> Result<Option<AccumulatorInstance>, ZkError> {
+        match ZkPlug::as_aggregator(self) {
+            Some(aggregator) => aggregator.extract_accumulator(proof_bytes, public_inputs).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    async fn fold_accumulators_erased(
+        &self,
+        accumulators: &[AccumulatorInstance],
+    ) -> Result<Option<Vec<u8>>, ZkError> {
+        match ZkPlug::as_aggregator(self) {
+            Some(aggregator) => aggregator.fold(accumulators).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// A registry for managing multiple heterogeneous ZK backends, keyed by [`ZkPlug::id`]. Plugins
+/// are stored behind the [`ErasedZkPlug`] adapter so backends with different associated
+/// `Proof`/`Error` types can live side by side.
+#[derive(Default)]
 pub struct ZkPluginRegistry {
-    plugins: HashMap<String, Arc<dyn ZkPlug<Proof = Box<dyn std::any::Any + Send + Sync>, Error = ZkError>>>,
+    plugins: HashMap<String, Arc<dyn ErasedZkPlug>>,
 }
 
 impl ZkPluginRegistry {
@@ -452,25 +1244,23 @@ impl ZkPluginRegistry {
         }
     }
 
-    /// Register a new ZK plugin
+    /// Register a new ZK plugin, wrapping it in the [`ErasedZkPlug`] adapter.
     pub fn register<P>(&mut self, plugin: Arc<P>) -> Result<(), ZkError>
     where
         P: ZkPlug + 'static,
-        P::Proof: 'static,
         P::Error: Into<ZkError>,
     {
         let id = plugin.id().to_string();
         if self.plugins.contains_key(&id) {
             return Err(ZkError::InvalidInput(format!("Plugin '{}' already registered", id)));
         }
-        
-        // This is a simplified version - in practice you'd need more sophisticated type erasure
-        // self.plugins.insert(id, plugin as Arc<dyn ZkPlug<...>>);
+
+        self.plugins.insert(id, plugin as Arc<dyn ErasedZkPlug>);
         Ok(())
     }
 
     /// Get a plugin by ID
-    pub fn get(&self, id: &str) -> Option<&Arc<dyn ZkPlug<Proof = Box<dyn std::any::Any + Send + Sync>, Error = ZkError>>> {
+    pub fn get(&self, id: &str) -> Option<&Arc<dyn ErasedZkPlug>> {
         self.plugins.get(id)
     }
 
@@ -480,9 +1270,136 @@ impl ZkPluginRegistry {
     }
 
     /// Unregister a plugin by ID
-    pub fn unregister(&mut self, id: &str) -> Option<Arc<dyn ZkPlug<Proof = Box<dyn std::any::Any + Send + Sync>, Error = ZkError>>> {
+    pub fn unregister(&mut self, id: &str) -> Option<Arc<dyn ErasedZkPlug>> {
         self.plugins.remove(id)
     }
+
+    /// Generate a proof on the named backend, returning opaque proof bytes plus metadata.
+    pub async fn prove(
+        &self,
+        id: &str,
+        input: &[u8],
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> Result<(Vec<u8>, ProofMetadata), ZkError> {
+        let plugin = self.plugins.get(id).ok_or_else(|| {
+            ZkError::BackendUnavailable(format!("no plugin registered for '{id}'"))
+        })?;
+        plugin.prove_erased(input, public_inputs, config).await
+    }
+
+    /// Verify a proof (given as opaque bytes) on the named backend.
+    pub async fn verify(
+        &self,
+        id: &str,
+        proof_bytes: &[u8],
+        public_inputs: Option<&[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> Result<bool, ZkError> {
+        let plugin = self.plugins.get(id).ok_or_else(|| {
+            ZkError::BackendUnavailable(format!("no plugin registered for '{id}'"))
+        })?;
+        plugin.verify_erased(proof_bytes, public_inputs, config).await
+    }
+
+    /// Combine proofs produced by *different* backends: look up each child's originating plugin
+    /// by id, verify the proof against it, then hand the verified bundle's accumulators to
+    /// `aggregator_id`'s plugin (via [`ErasedZkPlug::extract_accumulator_erased`]/
+    /// [`ErasedZkPlug::fold_accumulators_erased`]) to emit a single rolled-up proof. Mirrors
+    /// Raiko's `ProofType::aggregate_proofs` dispatch, letting Frostgate mix e.g. a fast STARK
+    /// prover for execution with a SNARK wrapper for on-chain verification.
+    ///
+    /// Each child's public inputs are passed as empty bytes to `verify_erased`/
+    /// `extract_accumulator_erased` — this wire format (mirroring the request's
+    /// `(backend_id, proof_bytes)` pairs) has no side channel for them, so only proof schemes
+    /// that embed/commit their public inputs within the proof bytes themselves verify soundly
+    /// here; callers with externally-supplied public inputs should verify each child directly
+    /// via [`ZkPluginRegistry::verify`] before calling this.
+    pub async fn aggregate_across(
+        &self,
+        inputs: &[(String, Vec<u8>)],
+        aggregator_id: &str,
+    ) -> Result<AggregationGuestOutput, ZkError> {
+        let aggregator = self.plugins.get(aggregator_id).ok_or_else(|| {
+            ZkError::BackendUnavailable(format!(
+                "no aggregator plugin registered for '{aggregator_id}'"
+            ))
+        })?;
+
+        let mut guest_inputs = Vec::with_capacity(inputs.len());
+        let mut accumulators = Vec::with_capacity(inputs.len());
+        for (backend_id, proof_bytes) in inputs {
+            let plugin = self.plugins.get(backend_id).ok_or_else(|| {
+                ZkError::BackendUnavailable(format!("no plugin registered for '{backend_id}'"))
+            })?;
+
+            if !plugin.verify_erased(proof_bytes, None, None).await? {
+                return Err(ZkError::VerificationFailed(format!(
+                    "child proof from '{backend_id}' failed verification"
+                )));
+            }
+
+            let accumulator = aggregator
+                .extract_accumulator_erased(proof_bytes, &[])
+                .await?
+                .ok_or_else(|| {
+                    ZkError::Unsupported(format!(
+                        "aggregator plugin '{aggregator_id}' does not implement accumulator extraction"
+                    ))
+                })?;
+            let circuit_hash = accumulator.circuit_hash.clone();
+
+            guest_inputs.push(AggregationGuestInput {
+                backend_id: backend_id.clone(),
+                proof_bytes: proof_bytes.clone(),
+                public_inputs: Vec::new(),
+                circuit_hash,
+            });
+            accumulators.push(accumulator);
+        }
+
+        let folded_bytes = aggregator
+            .fold_accumulators_erased(&accumulators)
+            .await?
+            .ok_or_else(|| {
+                ZkError::Unsupported(format!(
+                    "aggregator plugin '{aggregator_id}' does not implement accumulator folding"
+                ))
+            })?;
+
+        Ok(AggregationGuestOutput {
+            proof_bytes: folded_bytes,
+            child_backend_ids: guest_inputs.iter().map(|g| g.backend_id.clone()).collect(),
+            child_circuit_hashes: guest_inputs.iter().map(|g| g.circuit_hash.clone()).collect(),
+        })
+    }
+}
+
+/// One child proof being fed into a heterogeneous-backend [`ZkPluginRegistry::aggregate_across`]
+/// run: which plugin produced it, the raw proof bytes, its public inputs, and the circuit hash it
+/// was proven against.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregationGuestInput {
+    /// Id of the plugin that produced this proof
+    pub backend_id: String,
+    /// Raw, backend-specific proof bytes
+    pub proof_bytes: Vec<u8>,
+    /// Public inputs the proof was generated against
+    pub public_inputs: Vec<u8>,
+    /// Hash of the circuit/program this proof was generated against
+    pub circuit_hash: String,
+}
+
+/// Output of a [`ZkPluginRegistry::aggregate_across`] run: the rolled-up proof bytes plus which
+/// children were folded in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregationGuestOutput {
+    /// Raw bytes of the single aggregated proof
+    pub proof_bytes: Vec<u8>,
+    /// Ids of the plugins whose proofs were folded into this aggregate, in input order
+    pub child_backend_ids: Vec<String>,
+    /// Circuit hashes of the folded-in proofs, in input order
+    pub child_circuit_hashes: Vec<String>,
 }
 
 /// Utility functions for working with ZK backends
@@ -534,4 +1451,858 @@ pub mod utils {
 }
 
 // Re-export commonly used types
-pub use utils::*;
\ No newline at end of file
+pub use utils::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trivial `ZkPlug` whose "proof" is just the input bytes and whose "verification" checks the
+    /// proof against the claimed public inputs — enough to exercise [`ZkPluginRegistry`]'s
+    /// dispatch through the [`ErasedZkPlug`] adapter without any real cryptography.
+    #[derive(Debug)]
+    struct EchoPlug {
+        id: &'static str,
+    }
+
+    #[async_trait]
+    impl ZkPlug for EchoPlug {
+        type Proof = Vec<u8>;
+        type Error = ZkError;
+
+        async fn prove(
+            &self,
+            input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ZkProof<Self::Proof>, Self::Error> {
+            Ok(ZkProof {
+                proof: input.to_vec(),
+                metadata: ProofMetadata {
+                    timestamp: SystemTime::now(),
+                    generation_time: Duration::default(),
+                    proof_size: input.len(),
+                    backend_id: self.id.to_string(),
+                    circuit_hash: None,
+                    custom_fields: HashMap::new(),
+                },
+            })
+        }
+
+        async fn verify(
+            &self,
+            proof: &ZkProof<Self::Proof>,
+            public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<bool, Self::Error> {
+            Ok(public_inputs.is_none_or(|pi| pi == proof.proof.as_slice()))
+        }
+
+        async fn execute(
+            &self,
+            _program: &[u8],
+            _input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ExecutionResult<Self::Proof>, Self::Error> {
+            Err(ZkError::Unsupported("EchoPlug does not execute programs".to_string()))
+        }
+
+        async fn get_backend_info(&self) -> BackendInfo {
+            BackendInfo {
+                id: self.id.to_string(),
+                name: self.id.to_string(),
+                version: "test".to_string(),
+                capabilities: Vec::new(),
+                health: HealthStatus::Healthy,
+                resource_usage: ResourceUsage {
+                    cpu_usage: 0.0,
+                    memory_usage: 0,
+                    available_memory: usize::MAX,
+                    active_tasks: 0,
+                    queue_depth: 0,
+                },
+                custom_info: HashMap::new(),
+            }
+        }
+
+        fn id(&self) -> &'static str {
+            self.id
+        }
+
+        fn capabilities(&self) -> Vec<ZkCapability> {
+            Vec::new()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[tokio::test]
+    async fn registry_register_prove_verify_round_trip() {
+        let mut registry = ZkPluginRegistry::new();
+        registry.register(Arc::new(EchoPlug { id: "echo-a" })).unwrap();
+
+        assert_eq!(registry.list_plugins(), vec!["echo-a"]);
+        assert!(registry.get("echo-a").is_some());
+
+        let (proof_bytes, _metadata) =
+            registry.prove("echo-a", b"hello", None, None).await.unwrap();
+        let valid = registry
+            .verify("echo-a", &proof_bytes, Some(b"hello"), None)
+            .await
+            .unwrap();
+        assert!(valid);
+
+        let invalid = registry
+            .verify("echo-a", &proof_bytes, Some(b"not-hello"), None)
+            .await
+            .unwrap();
+        assert!(!invalid);
+    }
+
+    #[tokio::test]
+    async fn registry_register_rejects_duplicate_id() {
+        let mut registry = ZkPluginRegistry::new();
+        registry.register(Arc::new(EchoPlug { id: "echo-a" })).unwrap();
+        let err = registry
+            .register(Arc::new(EchoPlug { id: "echo-a" }))
+            .unwrap_err();
+        assert!(matches!(err, ZkError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn registry_prove_on_unknown_backend_fails() {
+        let registry = ZkPluginRegistry::new();
+        let err = registry
+            .prove("missing", b"hello", None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ZkError::BackendUnavailable(_)));
+    }
+
+    #[tokio::test]
+    async fn registry_unregister_removes_plugin() {
+        let mut registry = ZkPluginRegistry::new();
+        registry.register(Arc::new(EchoPlug { id: "echo-a" })).unwrap();
+        assert!(registry.unregister("echo-a").is_some());
+        assert!(registry.get("echo-a").is_none());
+    }
+
+    fn test_metadata(backend_id: &str, proof_size: usize) -> ProofMetadata {
+        ProofMetadata {
+            timestamp: SystemTime::now(),
+            generation_time: Duration::default(),
+            proof_size,
+            backend_id: backend_id.to_string(),
+            circuit_hash: None,
+            custom_fields: HashMap::new(),
+        }
+    }
+
+    /// Mock [`ZkAggregator`] whose "accumulator" is just the inner proof payload (decoded from
+    /// the serialized `ZkProof` the engine hands it), and whose "fold" is concatenation — enough
+    /// to exercise [`AggregationEngine`]'s dispatch and metadata bookkeeping without real
+    /// accumulator math.
+    struct MockAggregator;
+
+    #[async_trait]
+    impl ZkAggregator for MockAggregator {
+        async fn extract_accumulator(
+            &self,
+            proof: &[u8],
+            public_inputs: &[u8],
+        ) -> Result<AccumulatorInstance, ZkError> {
+            let decoded: ZkProof<Vec<u8>> = bincode::deserialize(proof)
+                .map_err(|e| ZkError::Serialization(e.to_string()))?;
+            Ok(AccumulatorInstance {
+                left: decoded.proof,
+                right: public_inputs.to_vec(),
+                public_inputs: public_inputs.to_vec(),
+                circuit_hash: hash_circuit(proof),
+            })
+        }
+
+        async fn fold(&self, accumulators: &[AccumulatorInstance]) -> Result<Vec<u8>, ZkError> {
+            let combined: Vec<u8> = accumulators.iter().flat_map(|a| a.left.clone()).collect();
+            bincode::serialize(&combined).map_err(|e| ZkError::Serialization(e.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn aggregation_engine_folds_proofs_and_records_metadata() {
+        let aggregator = MockAggregator;
+        let engine = AggregationEngine::new(&aggregator);
+
+        let proof_a = ZkProof {
+            proof: b"proof-a".to_vec(),
+            metadata: test_metadata("backend-a", 7),
+        };
+        let proof_b = ZkProof {
+            proof: b"proof-b".to_vec(),
+            metadata: test_metadata("backend-b", 7),
+        };
+
+        let aggregated = engine
+            .aggregate(&[(proof_a, b"pub-a".to_vec()), (proof_b, b"pub-b".to_vec())])
+            .await
+            .unwrap();
+
+        assert_eq!(aggregated.proof, [b"proof-a".as_slice(), b"proof-b".as_slice()].concat());
+        assert_eq!(
+            aggregated.metadata.custom_fields["aggregated_count"],
+            serde_json::Value::from(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn aggregation_engine_rejects_empty_input() {
+        let aggregator = MockAggregator;
+        let engine = AggregationEngine::new(&aggregator);
+        let err = engine.aggregate::<Vec<u8>>(&[]).await.unwrap_err();
+        assert!(matches!(err, ZkError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn lru_cache_evicts_least_recently_used() {
+        let cache = LruProofCache::new(2);
+        cache.put("a".to_string(), "circuit-1".to_string(), vec![1]).await;
+        cache.put("b".to_string(), "circuit-1".to_string(), vec![2]).await;
+        // Touch "a" so "b" becomes the least-recently-used entry.
+        assert_eq!(cache.get("a").await, Some(vec![1]));
+        cache.put("c".to_string(), "circuit-1".to_string(), vec![3]).await;
+
+        assert_eq!(cache.get("a").await, Some(vec![1]));
+        assert_eq!(cache.get("b").await, None);
+        assert_eq!(cache.get("c").await, Some(vec![3]));
+    }
+
+    #[tokio::test]
+    async fn lru_cache_invalidate_circuit_removes_matching_entries_only() {
+        let cache = LruProofCache::new(10);
+        cache.put("a".to_string(), "circuit-1".to_string(), vec![1]).await;
+        cache.put("b".to_string(), "circuit-2".to_string(), vec![2]).await;
+
+        cache.invalidate_circuit("circuit-1").await;
+
+        assert_eq!(cache.get("a").await, None);
+        assert_eq!(cache.get("b").await, Some(vec![2]));
+    }
+
+    #[tokio::test]
+    async fn lru_cache_clear_removes_everything() {
+        let cache = LruProofCache::new(10);
+        cache.put("a".to_string(), "circuit-1".to_string(), vec![1]).await;
+        cache.clear().await;
+        assert_eq!(cache.get("a").await, None);
+    }
+
+    #[tokio::test]
+    async fn disk_cache_round_trips_and_invalidates_by_circuit() {
+        let dir = std::env::temp_dir().join(format!(
+            "frostgate-zkip-disk-cache-test-{:?}",
+            std::thread::current().id()
+        ));
+        let cache = DiskProofCache::new(dir.clone());
+
+        cache.put("a".to_string(), "circuit-1".to_string(), vec![9, 8, 7]).await;
+        assert_eq!(cache.get("a").await, Some(vec![9, 8, 7]));
+
+        cache.invalidate_circuit("circuit-1").await;
+        assert_eq!(cache.get("a").await, None);
+
+        cache.clear().await;
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+
+    fn checkpoint(pc: u64, cycle_count: u64) -> VmCheckpoint {
+        VmCheckpoint { registers: Vec::new(), memory_root: Vec::new(), pc, cycle_count }
+    }
+
+    /// Mock [`SegmentedExecutor`] over a fixed, pre-built segment list: "proves" a segment as its
+    /// cycle count and "folds" by concatenating the decoded byte vectors, so the final root proof
+    /// bytes stay bincode-decodable as `Vec<u8>` however many levels the binary fold ran.
+    struct MockExecutor {
+        segments: Vec<VmSegment>,
+    }
+
+    impl std::fmt::Debug for MockExecutor {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockExecutor").finish()
+        }
+    }
+
+    #[async_trait]
+    impl SegmentedExecutor for MockExecutor {
+        async fn split_into_segments(
+            &self,
+            _program: &[u8],
+            _input: &[u8],
+        ) -> Result<(Vec<VmSegment>, Vec<u8>), ZkError> {
+            Ok((self.segments.clone(), b"output".to_vec()))
+        }
+
+        async fn prove_segment(&self, segment: &VmSegment) -> Result<Vec<u8>, ZkError> {
+            bincode::serialize(&vec![segment.state_in.cycle_count as u8])
+                .map_err(|e| ZkError::Serialization(e.to_string()))
+        }
+
+        async fn fold_segments(
+            &self,
+            left: (&VmCheckpoint, &VmCheckpoint, &[u8]),
+            right: (&VmCheckpoint, &VmCheckpoint, &[u8]),
+        ) -> Result<Vec<u8>, ZkError> {
+            let mut combined: Vec<u8> = bincode::deserialize(left.2)
+                .map_err(|e| ZkError::Serialization(e.to_string()))?;
+            let right_bytes: Vec<u8> = bincode::deserialize(right.2)
+                .map_err(|e| ZkError::Serialization(e.to_string()))?;
+            combined.extend(right_bytes);
+            bincode::serialize(&combined).map_err(|e| ZkError::Serialization(e.to_string()))
+        }
+    }
+
+    /// `ZkPlug` that delegates continuation-based execution to a [`MockExecutor`].
+    #[derive(Debug)]
+    struct ContinuationPlug {
+        executor: MockExecutor,
+    }
+
+    #[async_trait]
+    impl ZkPlug for ContinuationPlug {
+        type Proof = Vec<u8>;
+        type Error = ZkError;
+
+        async fn prove(
+            &self,
+            input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ZkProof<Self::Proof>, Self::Error> {
+            Ok(ZkProof { proof: input.to_vec(), metadata: test_metadata("continuation", input.len()) })
+        }
+
+        async fn verify(
+            &self,
+            _proof: &ZkProof<Self::Proof>,
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<bool, Self::Error> {
+            Ok(true)
+        }
+
+        async fn execute(
+            &self,
+            _program: &[u8],
+            _input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ExecutionResult<Self::Proof>, Self::Error> {
+            Err(ZkError::Unsupported("ContinuationPlug only supports execute_continuation".to_string()))
+        }
+
+        async fn get_backend_info(&self) -> BackendInfo {
+            BackendInfo {
+                id: self.id().to_string(),
+                name: self.id().to_string(),
+                version: "test".to_string(),
+                capabilities: self.capabilities(),
+                health: HealthStatus::Healthy,
+                resource_usage: ResourceUsage {
+                    cpu_usage: 0.0,
+                    memory_usage: 0,
+                    available_memory: usize::MAX,
+                    active_tasks: 0,
+                    queue_depth: 0,
+                },
+                custom_info: HashMap::new(),
+            }
+        }
+
+        fn id(&self) -> &'static str {
+            "continuation-mock"
+        }
+
+        fn capabilities(&self) -> Vec<ZkCapability> {
+            vec![ZkCapability::Incremental, ZkCapability::Recursion]
+        }
+
+        fn as_segmented_executor(&self) -> Option<&dyn SegmentedExecutor> {
+            Some(&self.executor)
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_continuation_folds_matching_segments() {
+        let plug = ContinuationPlug {
+            executor: MockExecutor {
+                segments: vec![
+                    VmSegment {
+                        state_in: checkpoint(0, 0),
+                        state_out: checkpoint(1, 10),
+                        segment_input: Vec::new(),
+                    },
+                    VmSegment {
+                        state_in: checkpoint(1, 10),
+                        state_out: checkpoint(2, 20),
+                        segment_input: Vec::new(),
+                    },
+                ],
+            },
+        };
+
+        let result = plug.execute_continuation(b"program", b"input", None).await.unwrap();
+        assert_eq!(result.stats.steps, 20);
+    }
+
+    #[tokio::test]
+    async fn execute_continuation_rejects_boundary_mismatch() {
+        let plug = ContinuationPlug {
+            executor: MockExecutor {
+                segments: vec![
+                    VmSegment {
+                        state_in: checkpoint(0, 0),
+                        state_out: checkpoint(1, 10),
+                        segment_input: Vec::new(),
+                    },
+                    VmSegment {
+                        // state_in's pc doesn't match the previous segment's state_out.
+                        state_in: checkpoint(99, 10),
+                        state_out: checkpoint(2, 20),
+                        segment_input: Vec::new(),
+                    },
+                ],
+            },
+        };
+
+        let err = plug.execute_continuation(b"program", b"input", None).await.unwrap_err();
+        assert!(matches!(err, ZkError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn execute_continuation_single_segment_skips_fold_loop() {
+        let plug = ContinuationPlug {
+            executor: MockExecutor {
+                segments: vec![VmSegment {
+                    state_in: checkpoint(0, 0),
+                    state_out: checkpoint(1, 10),
+                    segment_input: Vec::new(),
+                }],
+            },
+        };
+
+        let result = plug.execute_continuation(b"program", b"input", None).await.unwrap();
+        assert_eq!(result.stats.steps, 10);
+    }
+
+    /// [`ZkPlug`] whose `prove` fails for a specific marker input, used to exercise
+    /// `prove_batch`'s per-slot success/failure handling.
+    struct SelectivePlug;
+
+    #[async_trait]
+    impl ZkPlug for SelectivePlug {
+        type Proof = Vec<u8>;
+        type Error = ZkError;
+
+        async fn prove(
+            &self,
+            input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ZkProof<Self::Proof>, Self::Error> {
+            if input == b"fail" {
+                return Err(ZkError::ProofGeneration("forced failure".to_string()));
+            }
+            Ok(ZkProof { proof: input.to_vec(), metadata: test_metadata("selective", input.len()) })
+        }
+
+        async fn verify(
+            &self,
+            proof: &ZkProof<Self::Proof>,
+            public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<bool, Self::Error> {
+            if proof.proof == b"fail" {
+                return Err(ZkError::VerificationFailed("forced failure".to_string()));
+            }
+            Ok(public_inputs.is_none_or(|pi| pi == proof.proof.as_slice()))
+        }
+
+        async fn execute(
+            &self,
+            _program: &[u8],
+            _input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ExecutionResult<Self::Proof>, Self::Error> {
+            Err(ZkError::Unsupported("SelectivePlug does not execute programs".to_string()))
+        }
+
+        async fn get_backend_info(&self) -> BackendInfo {
+            BackendInfo {
+                id: self.id().to_string(),
+                name: self.id().to_string(),
+                version: "test".to_string(),
+                capabilities: Vec::new(),
+                health: HealthStatus::Healthy,
+                resource_usage: ResourceUsage {
+                    cpu_usage: 0.0,
+                    memory_usage: 0,
+                    available_memory: usize::MAX,
+                    active_tasks: 0,
+                    queue_depth: 0,
+                },
+                custom_info: HashMap::new(),
+            }
+        }
+
+        fn id(&self) -> &'static str {
+            "selective-mock"
+        }
+
+        fn capabilities(&self) -> Vec<ZkCapability> {
+            Vec::new()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    /// [`ZkPlug`] that declares an oversized `estimated_task_memory`, used to exercise
+    /// `batch_admission`'s single-task `ResourceExhaustion` rejection.
+    struct HeavyPlug;
+
+    #[async_trait]
+    impl ZkPlug for HeavyPlug {
+        type Proof = Vec<u8>;
+        type Error = ZkError;
+
+        async fn prove(
+            &self,
+            input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ZkProof<Self::Proof>, Self::Error> {
+            Ok(ZkProof { proof: input.to_vec(), metadata: test_metadata("heavy", input.len()) })
+        }
+
+        async fn verify(
+            &self,
+            _proof: &ZkProof<Self::Proof>,
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<bool, Self::Error> {
+            Ok(true)
+        }
+
+        async fn execute(
+            &self,
+            _program: &[u8],
+            _input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ExecutionResult<Self::Proof>, Self::Error> {
+            Err(ZkError::Unsupported("HeavyPlug does not execute programs".to_string()))
+        }
+
+        async fn get_backend_info(&self) -> BackendInfo {
+            BackendInfo {
+                id: self.id().to_string(),
+                name: self.id().to_string(),
+                version: "test".to_string(),
+                capabilities: Vec::new(),
+                health: HealthStatus::Healthy,
+                resource_usage: ResourceUsage {
+                    cpu_usage: 0.0,
+                    memory_usage: 0,
+                    available_memory: usize::MAX,
+                    active_tasks: 0,
+                    queue_depth: 0,
+                },
+                custom_info: HashMap::new(),
+            }
+        }
+
+        fn id(&self) -> &'static str {
+            "heavy-mock"
+        }
+
+        fn capabilities(&self) -> Vec<ZkCapability> {
+            Vec::new()
+        }
+
+        fn estimated_task_memory(&self) -> Option<usize> {
+            Some(1_000_000)
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[tokio::test]
+    async fn prove_batch_rejects_oversized_single_task() {
+        let plug = HeavyPlug;
+        let config = ZkConfig { memory_limit: Some(100), ..Default::default() };
+        let request = BatchProvingRequest {
+            inputs: vec![b"only-task".to_vec()],
+            public_inputs: vec![None],
+            config: Some(config),
+        };
+
+        let err = plug.prove_batch(&request).await.unwrap_err();
+        assert!(matches!(err, ZkError::ResourceExhaustion(_)));
+    }
+
+    #[tokio::test]
+    async fn prove_batch_preserves_order_with_mixed_success_and_failure() {
+        let plug = SelectivePlug;
+        let request = BatchProvingRequest {
+            inputs: vec![b"ok-1".to_vec(), b"fail".to_vec(), b"ok-2".to_vec()],
+            public_inputs: vec![None, None, None],
+            config: None,
+        };
+
+        let result = plug.prove_batch(&request).await.unwrap();
+        assert_eq!(result.proofs.len(), 3);
+        assert_eq!(result.proofs[0].as_ref().unwrap().proof, b"ok-1".to_vec());
+        assert!(result.proofs[1].is_err());
+        assert_eq!(result.proofs[2].as_ref().unwrap().proof, b"ok-2".to_vec());
+    }
+
+    #[tokio::test]
+    async fn verify_batch_preserves_order_with_mixed_pass_and_fail() {
+        let plug = SelectivePlug;
+        let proofs = vec![
+            ZkProof { proof: b"ok-1".to_vec(), metadata: test_metadata("selective", 4) },
+            ZkProof { proof: b"ok-2".to_vec(), metadata: test_metadata("selective", 4) },
+            ZkProof { proof: b"ok-3".to_vec(), metadata: test_metadata("selective", 4) },
+        ];
+        let public_inputs =
+            vec![Some(b"ok-1".to_vec()), Some(b"mismatch".to_vec()), Some(b"ok-3".to_vec())];
+
+        let results = plug.verify_batch(&proofs, &public_inputs, None).await.unwrap();
+        assert_eq!(results, vec![true, false, true]);
+    }
+
+    /// [`ZkPlug`] that is also its own [`ZkAggregator`], folding child accumulators by
+    /// concatenating their `left` halves — enough to exercise
+    /// [`ZkPluginRegistry::aggregate_across`]'s dispatch without real accumulator math.
+    struct AggregatorPlug;
+
+    #[async_trait]
+    impl ZkAggregator for AggregatorPlug {
+        async fn extract_accumulator(
+            &self,
+            proof: &[u8],
+            public_inputs: &[u8],
+        ) -> Result<AccumulatorInstance, ZkError> {
+            Ok(AccumulatorInstance {
+                left: proof.to_vec(),
+                right: public_inputs.to_vec(),
+                public_inputs: public_inputs.to_vec(),
+                circuit_hash: hash_circuit(proof),
+            })
+        }
+
+        async fn fold(&self, accumulators: &[AccumulatorInstance]) -> Result<Vec<u8>, ZkError> {
+            let combined: Vec<u8> = accumulators.iter().flat_map(|a| a.left.clone()).collect();
+            bincode::serialize(&combined).map_err(|e| ZkError::Serialization(e.to_string()))
+        }
+    }
+
+    #[async_trait]
+    impl ZkPlug for AggregatorPlug {
+        type Proof = Vec<u8>;
+        type Error = ZkError;
+
+        async fn prove(
+            &self,
+            input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ZkProof<Self::Proof>, Self::Error> {
+            Ok(ZkProof { proof: input.to_vec(), metadata: test_metadata("aggregator", input.len()) })
+        }
+
+        async fn verify(
+            &self,
+            _proof: &ZkProof<Self::Proof>,
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<bool, Self::Error> {
+            Ok(true)
+        }
+
+        async fn execute(
+            &self,
+            _program: &[u8],
+            _input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ExecutionResult<Self::Proof>, Self::Error> {
+            Err(ZkError::Unsupported("AggregatorPlug does not execute programs".to_string()))
+        }
+
+        async fn get_backend_info(&self) -> BackendInfo {
+            BackendInfo {
+                id: self.id().to_string(),
+                name: self.id().to_string(),
+                version: "test".to_string(),
+                capabilities: self.capabilities(),
+                health: HealthStatus::Healthy,
+                resource_usage: ResourceUsage {
+                    cpu_usage: 0.0,
+                    memory_usage: 0,
+                    available_memory: usize::MAX,
+                    active_tasks: 0,
+                    queue_depth: 0,
+                },
+                custom_info: HashMap::new(),
+            }
+        }
+
+        fn id(&self) -> &'static str {
+            "aggregator-mock"
+        }
+
+        fn capabilities(&self) -> Vec<ZkCapability> {
+            vec![ZkCapability::UniversalSetup, ZkCapability::Aggregation]
+        }
+
+        fn as_aggregator(&self) -> Option<&dyn ZkAggregator> {
+            Some(self)
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    /// [`ZkPlug`] whose `verify` always rejects, used to exercise `aggregate_across`'s
+    /// child-verification-failure path.
+    struct AlwaysRejectPlug;
+
+    #[async_trait]
+    impl ZkPlug for AlwaysRejectPlug {
+        type Proof = Vec<u8>;
+        type Error = ZkError;
+
+        async fn prove(
+            &self,
+            input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ZkProof<Self::Proof>, Self::Error> {
+            Ok(ZkProof {
+                proof: input.to_vec(),
+                metadata: test_metadata("always-reject", input.len()),
+            })
+        }
+
+        async fn verify(
+            &self,
+            _proof: &ZkProof<Self::Proof>,
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<bool, Self::Error> {
+            Ok(false)
+        }
+
+        async fn execute(
+            &self,
+            _program: &[u8],
+            _input: &[u8],
+            _public_inputs: Option<&[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> Result<ExecutionResult<Self::Proof>, Self::Error> {
+            Err(ZkError::Unsupported("AlwaysRejectPlug does not execute programs".to_string()))
+        }
+
+        async fn get_backend_info(&self) -> BackendInfo {
+            BackendInfo {
+                id: self.id().to_string(),
+                name: self.id().to_string(),
+                version: "test".to_string(),
+                capabilities: Vec::new(),
+                health: HealthStatus::Healthy,
+                resource_usage: ResourceUsage {
+                    cpu_usage: 0.0,
+                    memory_usage: 0,
+                    available_memory: usize::MAX,
+                    active_tasks: 0,
+                    queue_depth: 0,
+                },
+                custom_info: HashMap::new(),
+            }
+        }
+
+        fn id(&self) -> &'static str {
+            "always-reject-mock"
+        }
+
+        fn capabilities(&self) -> Vec<ZkCapability> {
+            Vec::new()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[tokio::test]
+    async fn aggregate_across_folds_child_proofs_from_different_backends() {
+        let mut registry = ZkPluginRegistry::new();
+        registry.register(Arc::new(EchoPlug { id: "echo-a" })).unwrap();
+        registry.register(Arc::new(EchoPlug { id: "echo-b" })).unwrap();
+        registry.register(Arc::new(AggregatorPlug)).unwrap();
+
+        let (proof_a, _) = registry.prove("echo-a", b"proof-a", None, None).await.unwrap();
+        let (proof_b, _) = registry.prove("echo-b", b"proof-b", None, None).await.unwrap();
+
+        let output = registry
+            .aggregate_across(
+                &[("echo-a".to_string(), proof_a), ("echo-b".to_string(), proof_b)],
+                "aggregator-mock",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(output.child_backend_ids, vec!["echo-a".to_string(), "echo-b".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn aggregate_across_rejects_child_proof_failing_verification() {
+        let mut registry = ZkPluginRegistry::new();
+        registry.register(Arc::new(AlwaysRejectPlug)).unwrap();
+        registry.register(Arc::new(AggregatorPlug)).unwrap();
+
+        let (proof_bytes, _) =
+            registry.prove("always-reject-mock", b"input", None, None).await.unwrap();
+
+        let err = registry
+            .aggregate_across(&[("always-reject-mock".to_string(), proof_bytes)], "aggregator-mock")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ZkError::VerificationFailed(_)));
+    }
+
+    #[tokio::test]
+    async fn aggregate_across_rejects_aggregator_without_accumulator_hooks() {
+        let mut registry = ZkPluginRegistry::new();
+        registry.register(Arc::new(EchoPlug { id: "echo-a" })).unwrap();
+
+        let (proof_bytes, _) = registry.prove("echo-a", b"proof-a", None, None).await.unwrap();
+
+        let err = registry
+            .aggregate_across(&[("echo-a".to_string(), proof_bytes)], "echo-a")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ZkError::Unsupported(_)));
+    }
+}
\ No newline at end of file